@@ -0,0 +1,287 @@
+use crate::prelude::*;
+
+/// An axis-aligned box that a shape fits entirely within.
+#[derive(Debug, Clone, Copy)]
+pub struct BoundingBox {
+    minimum: Point,
+    maximum: Point,
+}
+
+impl BoundingBox {
+    pub fn new(minimum: Point, maximum: Point) -> Self {
+        BoundingBox { minimum, maximum }
+    }
+
+    /// A box with nothing in it, that will become the size of the first point or box added to it.
+    pub fn empty() -> Self {
+        BoundingBox::new(
+            Point::new(f64::INFINITY, f64::INFINITY, f64::INFINITY),
+            Point::new(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY),
+        )
+    }
+
+    /// A box that contains all of space, for shapes that go on forever (like planes).
+    pub fn infinite() -> Self {
+        BoundingBox::new(
+            Point::new(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY),
+            Point::new(f64::INFINITY, f64::INFINITY, f64::INFINITY),
+        )
+    }
+
+    pub fn minimum(&self) -> &Point {
+        &self.minimum
+    }
+
+    pub fn maximum(&self) -> &Point {
+        &self.maximum
+    }
+
+    pub fn is_finite(&self) -> bool {
+        [self.minimum, self.maximum]
+            .iter()
+            .all(|point| point.x().is_finite() && point.y().is_finite() && point.z().is_finite())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.minimum.x() > self.maximum.x()
+            || self.minimum.y() > self.maximum.y()
+            || self.minimum.z() > self.maximum.z()
+    }
+
+    pub fn add_point(&mut self, point: Point) {
+        self.minimum = Point::new(
+            self.minimum.x().min(*point.x()),
+            self.minimum.y().min(*point.y()),
+            self.minimum.z().min(*point.z()),
+        );
+        self.maximum = Point::new(
+            self.maximum.x().max(*point.x()),
+            self.maximum.y().max(*point.y()),
+            self.maximum.z().max(*point.z()),
+        );
+    }
+
+    pub fn add_box(&mut self, other: &BoundingBox) {
+        self.add_point(other.minimum);
+        self.add_point(other.maximum);
+    }
+
+    pub fn center(&self) -> Point {
+        Point::new(
+            (self.minimum.x() + self.maximum.x()) / 2.0,
+            (self.minimum.y() + self.maximum.y()) / 2.0,
+            (self.minimum.z() + self.maximum.z()) / 2.0,
+        )
+    }
+
+    /// Finds the box that contains this one after it's been transformed. Boxes that go on forever
+    /// just become infinite, since there's no telling which way they'll end up going.
+    pub fn transformed(&self, transform: &Transform) -> BoundingBox {
+        if self.is_empty() {
+            return *self;
+        }
+
+        if !self.is_finite() {
+            return BoundingBox::infinite();
+        }
+
+        let mut transformed = BoundingBox::empty();
+
+        for x in [self.minimum.x(), self.maximum.x()] {
+            for y in [self.minimum.y(), self.maximum.y()] {
+                for z in [self.minimum.z(), self.maximum.z()] {
+                    transformed.add_point(*transform * Point::new(*x, *y, *z));
+                }
+            }
+        }
+
+        transformed
+    }
+
+    /// Checks if the line the ray is on passes through the box at all, even behind the origin of
+    /// the ray, since every intersection along that line can matter (like for refraction).
+    pub fn intersects(&self, ray: &Ray) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+
+        let origin = ray.origin();
+        let direction = ray.direction();
+
+        let (x_min, x_max) = check_axis(
+            *origin.x(),
+            *direction.x(),
+            *self.minimum.x(),
+            *self.maximum.x(),
+        );
+        let (y_min, y_max) = check_axis(
+            *origin.y(),
+            *direction.y(),
+            *self.minimum.y(),
+            *self.maximum.y(),
+        );
+        let (z_min, z_max) = check_axis(
+            *origin.z(),
+            *direction.z(),
+            *self.minimum.z(),
+            *self.maximum.z(),
+        );
+
+        let t_min = x_min.max(y_min).max(z_min);
+        let t_max = x_max.min(y_max).min(z_max);
+
+        // A little leeway so that rays that just graze the box still count.
+        t_min <= t_max + EPSILON
+    }
+}
+
+// Just like the check for cubes, but for a slab with any limits.
+fn check_axis(origin: f64, direction: f64, minimum: f64, maximum: f64) -> (f64, f64) {
+    // A ray parallel to the slab is either always in it or never is.
+    if direction.abs() < EPSILON {
+        return if minimum - EPSILON <= origin && origin <= maximum + EPSILON {
+            (f64::NEG_INFINITY, f64::INFINITY)
+        } else {
+            (f64::INFINITY, f64::NEG_INFINITY)
+        };
+    }
+
+    let t_min = (minimum - origin) / direction;
+    let t_max = (maximum - origin) / direction;
+
+    if t_min > t_max {
+        (t_max, t_min)
+    } else {
+        (t_min, t_max)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::f64::consts::{FRAC_1_SQRT_2, PI, SQRT_2};
+
+    #[test]
+    fn adding_points_to_an_empty_box() {
+        let mut bounding_box = BoundingBox::empty();
+
+        bounding_box.add_point(Point::new(-5.0, 2.0, 0.0));
+        bounding_box.add_point(Point::new(7.0, 0.0, -3.0));
+
+        assert_eq!(*bounding_box.minimum(), Point::new(-5.0, 0.0, -3.0));
+        assert_eq!(*bounding_box.maximum(), Point::new(7.0, 2.0, 0.0));
+    }
+
+    #[test]
+    fn adding_one_box_to_another() {
+        let mut first = BoundingBox::new(Point::new(-5.0, -2.0, 0.0), Point::new(7.0, 4.0, 4.0));
+        let second = BoundingBox::new(Point::new(8.0, -7.0, -2.0), Point::new(14.0, 2.0, 8.0));
+
+        first.add_box(&second);
+
+        assert_eq!(*first.minimum(), Point::new(-5.0, -7.0, -2.0));
+        assert_eq!(*first.maximum(), Point::new(14.0, 4.0, 8.0));
+    }
+
+    #[test]
+    fn transforming_a_box() {
+        let bounding_box =
+            BoundingBox::new(Point::new(-1.0, -1.0, -1.0), Point::new(1.0, 1.0, 1.0));
+        let transform = Transform::x_rotation(PI / 4.0) * Transform::y_rotation(PI / 4.0);
+
+        let transformed = bounding_box.transformed(&transform);
+
+        let half_height = 1.0 + FRAC_1_SQRT_2;
+        assert_eq!(
+            *transformed.minimum(),
+            Point::new(-SQRT_2, -half_height, -half_height)
+        );
+        assert_eq!(
+            *transformed.maximum(),
+            Point::new(SQRT_2, half_height, half_height)
+        );
+    }
+
+    #[test]
+    fn transforming_an_infinite_box_keeps_it_infinite() {
+        let plane_bounds = BoundingBox::new(
+            Point::new(f64::NEG_INFINITY, 0.0, f64::NEG_INFINITY),
+            Point::new(f64::INFINITY, 0.0, f64::INFINITY),
+        );
+
+        let transformed = plane_bounds.transformed(&Transform::z_rotation(PI / 2.0));
+
+        assert!(!transformed.is_finite());
+        assert_eq!(*transformed.minimum().y(), f64::NEG_INFINITY);
+        assert_eq!(*transformed.maximum().y(), f64::INFINITY);
+    }
+
+    #[test]
+    fn intersecting_a_ray_with_a_box() {
+        let bounding_box = BoundingBox::new(Point::new(5.0, -2.0, 0.0), Point::new(11.0, 4.0, 7.0));
+
+        let cases = [
+            (Point::new(15.0, 1.0, 2.0), NEGATIVE_X, true),
+            (Point::new(-5.0, -1.0, 4.0), POSITIVE_X, true),
+            (Point::new(7.0, 6.0, 5.0), NEGATIVE_Y, true),
+            (Point::new(9.0, -5.0, 6.0), POSITIVE_Y, true),
+            (Point::new(8.0, 2.0, 12.0), NEGATIVE_Z, true),
+            (Point::new(6.0, 0.0, -5.0), POSITIVE_Z, true),
+            (Point::new(8.0, 1.0, 3.5), POSITIVE_Z, true),
+            (
+                Point::new(9.0, -1.0, -8.0),
+                Vector::new(2.0, 4.0, 6.0),
+                false,
+            ),
+            (
+                Point::new(8.0, 3.0, -4.0),
+                Vector::new(6.0, 2.0, 4.0),
+                false,
+            ),
+            (
+                Point::new(9.0, -1.0, -2.0),
+                Vector::new(4.0, 6.0, 2.0),
+                false,
+            ),
+            (Point::new(4.0, 0.0, 9.0), NEGATIVE_Z, false),
+            (Point::new(8.0, 6.0, -1.0), NEGATIVE_Y, false),
+            (Point::new(12.0, 5.0, 4.0), NEGATIVE_X, false),
+        ];
+
+        for (origin, direction, expected) in cases {
+            let ray = Ray::new(origin, direction.normalize());
+            assert_eq!(
+                bounding_box.intersects(&ray),
+                expected,
+                "ray from {:?}",
+                origin
+            );
+        }
+    }
+
+    #[test]
+    fn a_box_behind_the_ray_is_still_intersected() {
+        let bounding_box =
+            BoundingBox::new(Point::new(-1.0, -1.0, -1.0), Point::new(1.0, 1.0, 1.0));
+        let ray = Ray::new(Point::new(0.0, 0.0, 5.0), POSITIVE_Z);
+
+        assert!(bounding_box.intersects(&ray));
+    }
+
+    #[test]
+    fn an_empty_box_is_never_intersected() {
+        let ray = Ray::new(ORIGIN, POSITIVE_Z);
+
+        assert!(!BoundingBox::empty().intersects(&ray));
+    }
+
+    #[test]
+    fn an_infinite_box_is_always_intersected() {
+        let ray = Ray::new(
+            Point::new(100.0, -30.0, 2.0),
+            Vector::new(1.0, 2.0, 3.0).normalize(),
+        );
+
+        assert!(BoundingBox::infinite().intersects(&ray));
+    }
+}
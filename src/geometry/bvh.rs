@@ -0,0 +1,246 @@
+use std::rc::Rc;
+
+use crate::prelude::*;
+
+// How many shapes can end up together at the bottom of the hierarchy before we split them up.
+const MAXIMUM_SHAPES_PER_LEAF: usize = 4;
+
+/// A bounding volume hierarchy over a list of shapes, so that a ray only has to be checked against
+/// the shapes whose bounding boxes it actually passes through.
+///
+/// The bounds of the shapes are found in the space the shapes themselves are in (e.g. world space
+/// for the shapes in a world, or the space of a group for its children).
+pub struct Bvh {
+    shapes: Vec<Rc<dyn Shape>>,
+    // Shapes that go on forever (like planes) can't be put in a box, so they're always checked.
+    unbounded: Vec<usize>,
+    root: Option<BvhNode>,
+}
+
+enum BvhNode {
+    Leaf {
+        bounds: BoundingBox,
+        shapes: Vec<usize>,
+    },
+    Branch {
+        bounds: BoundingBox,
+        left: Box<BvhNode>,
+        right: Box<BvhNode>,
+    },
+}
+
+impl Bvh {
+    pub fn new(shapes: &[Rc<dyn Shape>]) -> Self {
+        let mut bounded: Vec<(usize, BoundingBox)> = Vec::new();
+        let mut unbounded = Vec::new();
+
+        for (index, shape) in shapes.iter().enumerate() {
+            let bounds = shape.parent_space_bounds();
+
+            // Nothing can hit a shape with nothing in it (like an empty group).
+            if bounds.is_empty() {
+                continue;
+            }
+
+            if bounds.is_finite() {
+                bounded.push((index, bounds));
+            } else {
+                unbounded.push(index);
+            }
+        }
+
+        let root = if bounded.is_empty() {
+            None
+        } else {
+            Some(BvhNode::build(bounded))
+        };
+
+        Bvh {
+            shapes: shapes.to_vec(),
+            unbounded,
+            root,
+        }
+    }
+
+    /// Gets every shape that the ray might intersect, in the same order they were given in.
+    pub fn shapes_along(&self, ray: &Ray) -> Vec<&Rc<dyn Shape>> {
+        let mut indexes = self.unbounded.clone();
+
+        if let Some(root) = &self.root {
+            root.collect_along(ray, &mut indexes);
+        }
+
+        indexes.sort_unstable();
+
+        indexes.iter().map(|index| &self.shapes[*index]).collect()
+    }
+
+    /// The box around every shape in the hierarchy, which is infinite if any of them are.
+    pub fn bounds(&self) -> BoundingBox {
+        if !self.unbounded.is_empty() {
+            return BoundingBox::infinite();
+        }
+
+        match &self.root {
+            Some(root) => *root.bounds(),
+            None => BoundingBox::empty(),
+        }
+    }
+}
+
+impl BvhNode {
+    // Splits the shapes in half along the axis that their centers are most spread out on, until
+    // there are few enough of them to be put in a leaf.
+    fn build(mut shapes: Vec<(usize, BoundingBox)>) -> Self {
+        let mut bounds = BoundingBox::empty();
+        let mut centers = BoundingBox::empty();
+
+        for (_, shape_bounds) in &shapes {
+            bounds.add_box(shape_bounds);
+            centers.add_point(shape_bounds.center());
+        }
+
+        if shapes.len() <= MAXIMUM_SHAPES_PER_LEAF {
+            return BvhNode::Leaf {
+                bounds,
+                shapes: shapes.iter().map(|(index, _)| *index).collect(),
+            };
+        }
+
+        let spread = *centers.maximum() - *centers.minimum();
+        let axis_of: fn(&Point) -> f64 = if spread.x() >= spread.y() && spread.x() >= spread.z() {
+            |point| *point.x()
+        } else if spread.y() >= spread.z() {
+            |point| *point.y()
+        } else {
+            |point| *point.z()
+        };
+
+        shapes.sort_by(|(_, a), (_, b)| axis_of(&a.center()).total_cmp(&axis_of(&b.center())));
+        let right_shapes = shapes.split_off(shapes.len() / 2);
+
+        BvhNode::Branch {
+            bounds,
+            left: Box::new(BvhNode::build(shapes)),
+            right: Box::new(BvhNode::build(right_shapes)),
+        }
+    }
+
+    fn bounds(&self) -> &BoundingBox {
+        match self {
+            BvhNode::Leaf { bounds, .. } => bounds,
+            BvhNode::Branch { bounds, .. } => bounds,
+        }
+    }
+
+    fn collect_along(&self, ray: &Ray, indexes: &mut Vec<usize>) {
+        if !self.bounds().intersects(ray) {
+            return;
+        }
+
+        match self {
+            BvhNode::Leaf { shapes, .. } => indexes.extend(shapes),
+            BvhNode::Branch { left, right, .. } => {
+                left.collect_along(ray, indexes);
+                right.collect_along(ray, indexes);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sphere_at(x: f64, y: f64, z: f64) -> Rc<dyn Shape> {
+        let mut sphere = Sphere::new();
+        sphere.set_transform(Transform::translation(x, y, z));
+        Rc::new(sphere)
+    }
+
+    #[test]
+    fn only_the_shapes_near_the_ray_are_given_back() {
+        let mut shapes = Vec::new();
+        for x in 0..100 {
+            for y in 0..100 {
+                shapes.push(sphere_at(x as f64 * 3.0, y as f64 * 3.0, 0.0));
+            }
+        }
+
+        let bvh = Bvh::new(&shapes);
+
+        let ray = Ray::new(Point::new(30.0, 60.0, -5.0), POSITIVE_Z);
+        let shapes_along_ray = bvh.shapes_along(&ray);
+
+        assert!(shapes_along_ray.len() < 20);
+        assert!(shapes_along_ray
+            .iter()
+            .any(|shape| Rc::ptr_eq(shape, &shapes[10 * 100 + 20])));
+    }
+
+    #[test]
+    fn shapes_are_given_back_in_their_original_order() {
+        let shapes: Vec<Rc<dyn Shape>> = (0..20)
+            .map(|i| sphere_at(0.0, 0.0, (19 - i) as f64 * 3.0))
+            .collect();
+
+        let bvh = Bvh::new(&shapes);
+
+        let ray = Ray::new(Point::new(0.0, 0.0, -5.0), POSITIVE_Z);
+        let shapes_along_ray = bvh.shapes_along(&ray);
+
+        assert_eq!(shapes_along_ray.len(), 20);
+        for (shape, original) in shapes_along_ray.iter().zip(shapes.iter()) {
+            assert!(Rc::ptr_eq(shape, original));
+        }
+    }
+
+    #[test]
+    fn unbounded_shapes_are_always_given_back() {
+        let shapes: Vec<Rc<dyn Shape>> = vec![sphere_at(0.0, 0.0, 0.0), Rc::new(Plane::new())];
+
+        let bvh = Bvh::new(&shapes);
+
+        let ray = Ray::new(Point::new(10.0, 10.0, 0.0), POSITIVE_X);
+        let shapes_along_ray = bvh.shapes_along(&ray);
+
+        assert_eq!(shapes_along_ray.len(), 1);
+        assert_eq!(shapes_along_ray[0].shape_type(), ShapeType::Plane);
+        assert!(!bvh.bounds().is_finite());
+    }
+
+    #[test]
+    fn a_bvh_finds_the_same_hits_as_checking_every_shape_from_far_fewer_candidates() {
+        // A wall of 10,000 spheres, 100 by 100, with every ray going through one of them.
+        let mut shapes = Vec::new();
+        for x in 0..100 {
+            for y in 0..100 {
+                shapes.push(sphere_at(x as f64 * 3.0, y as f64 * 3.0, 0.0));
+            }
+        }
+
+        let bvh = Bvh::new(&shapes);
+
+        for i in 0..10 {
+            let origin = Point::new(i as f64 * 27.0 + 0.5, i as f64 * 21.0 + 0.5, -5.0);
+            let ray = Ray::new(origin, POSITIVE_Z);
+
+            let candidates = bvh.shapes_along(&ray);
+            assert!(candidates.len() < 20);
+
+            let flat_hits: Vec<f64> = shapes
+                .iter()
+                .flat_map(|shape| Intersection::of(shape, &ray))
+                .map(|intersection| *intersection.t())
+                .collect();
+            let bvh_hits: Vec<f64> = candidates
+                .into_iter()
+                .flat_map(|shape| Intersection::of(shape, &ray))
+                .map(|intersection| *intersection.t())
+                .collect();
+
+            assert_eq!(flat_hits.len(), 2);
+            assert_eq!(bvh_hits, flat_hits);
+        }
+    }
+}
@@ -0,0 +1,281 @@
+use crate::prelude::*;
+
+#[derive(Debug, PartialEq)]
+pub struct Cone {
+    minimum: f64,
+    maximum: f64,
+    closed: bool,
+    transform: Transform,
+    material: Material,
+    visibility: Visibility,
+}
+
+impl Cone {
+    pub fn new() -> Self {
+        Cone {
+            minimum: f64::NEG_INFINITY,
+            maximum: f64::INFINITY,
+            closed: false,
+            transform: Transform::new(IDENTITY_MATRIX),
+            material: MaterialBuilder::new().build(),
+            visibility: Visibility::default(),
+        }
+    }
+
+    /// Creates a cone that only extends between `minimum` and `maximum` along the y axis in
+    /// object space (exclusive of the limits themselves). If `closed` is true, the ends are capped.
+    pub fn new_truncated(minimum: f64, maximum: f64, closed: bool) -> Self {
+        Cone {
+            minimum,
+            maximum,
+            closed,
+            ..Cone::new()
+        }
+    }
+
+    fn intersect_caps(&self, ray: &Ray, times: &mut Vec<f64>) {
+        if !self.closed || close_enough(ray.direction().y(), &0.0) {
+            return;
+        }
+
+        for cap in [self.minimum, self.maximum] {
+            let t = (cap - ray.origin().y()) / ray.direction().y();
+            // The radius of a cone at any given height is the absolute value of that height.
+            if is_within_radius(ray, t, cap.abs()) {
+                times.push(t);
+            }
+        }
+    }
+}
+
+impl Shape for Cone {
+    fn normal_at(&self, world_space_point: Point, _uv: Option<(f64, f64)>) -> Vector {
+        let transform_inverse = self.transform.invert().unwrap();
+        let object_space_point = transform_inverse * world_space_point;
+
+        let x = *object_space_point.x();
+        let y = *object_space_point.y();
+        let z = *object_space_point.z();
+
+        let distance_from_y_axis = x.powi(2) + z.powi(2);
+
+        let object_space_normal =
+            if distance_from_y_axis < self.maximum.powi(2) && y >= self.maximum - EPSILON {
+                POSITIVE_Y
+            } else if distance_from_y_axis < self.minimum.powi(2) && y <= self.minimum + EPSILON {
+                NEGATIVE_Y
+            } else {
+                let mut normal_y = distance_from_y_axis.sqrt();
+                if y > 0.0 {
+                    normal_y = -normal_y;
+                }
+
+                Vector::new(x, normal_y, z)
+            };
+
+        let world_space_normal = transform_inverse.matrix().transpose() * object_space_normal;
+
+        world_space_normal.normalize()
+    }
+
+    fn times_of_intersections_with<'s, 'r>(&'s self, ray: &'r Ray) -> Vec<f64>
+    where
+        'r: 's,
+    {
+        let local_ray = self.transform.invert().unwrap() * ray;
+        let origin = local_ray.origin();
+        let direction = local_ray.direction();
+
+        let mut times = Vec::new();
+
+        let a = direction.x().powi(2) - direction.y().powi(2) + direction.z().powi(2);
+        let b = 2.0 * origin.x() * direction.x() - 2.0 * origin.y() * direction.y()
+            + 2.0 * origin.z() * direction.z();
+        let c = origin.x().powi(2) - origin.y().powi(2) + origin.z().powi(2);
+
+        let mut possible_times = Vec::new();
+
+        if close_enough(&a, &0.0) {
+            // The ray is parallel to one of the cone's halves, so it can cross the other half at
+            // most once. If `b` is also zero, it misses the walls entirely.
+            if !close_enough(&b, &0.0) {
+                possible_times.push(-c / (2.0 * b));
+            }
+        } else {
+            let discriminant = b.powi(2) - 4.0 * a * c;
+
+            if discriminant < 0.0 {
+                return times;
+            }
+
+            let t0 = (-b - discriminant.sqrt()) / (2.0 * a);
+            let t1 = (-b + discriminant.sqrt()) / (2.0 * a);
+
+            possible_times.push(t0.min(t1));
+            possible_times.push(t0.max(t1));
+        }
+
+        for t in possible_times {
+            let y = origin.y() + t * direction.y();
+            if self.minimum < y && y < self.maximum {
+                times.push(t);
+            }
+        }
+
+        self.intersect_caps(&local_ray, &mut times);
+
+        times
+    }
+
+    fn transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    fn set_transform(&mut self, transformation: Transform) {
+        self.transform = transformation;
+    }
+
+    fn material(&self) -> &Material {
+        &self.material
+    }
+
+    fn set_material(&mut self, material: Material) {
+        self.material = material;
+    }
+
+    fn visibility(&self) -> &Visibility {
+        &self.visibility
+    }
+
+    fn set_visibility(&mut self, visibility: Visibility) {
+        self.visibility = visibility;
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        // The radius of a cone at any height is the same as that height.
+        let radius = self.minimum.abs().max(self.maximum.abs());
+
+        BoundingBox::new(
+            Point::new(-radius, self.minimum, -radius),
+            Point::new(radius, self.maximum, radius),
+        )
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::Cone
+    }
+}
+
+// Checks if the point on the ray at time `t` is within the given radius of the y axis.
+fn is_within_radius(ray: &Ray, t: f64, radius: f64) -> bool {
+    let x = ray.origin().x() + t * ray.direction().x();
+    let z = ray.origin().z() + t * ray.direction().z();
+
+    x.powi(2) + z.powi(2) <= radius.powi(2)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn intersecting_a_cone_with_a_ray() {
+        let cone = Cone::new();
+
+        let cases = [
+            (Point::new(0.0, 0.0, -5.0), POSITIVE_Z, 5.0, 5.0),
+            (
+                Point::new(0.0, 0.0, -5.0),
+                Vector::new(1.0, 1.0, 1.0),
+                8.66025,
+                8.66025,
+            ),
+            (
+                Point::new(1.0, 1.0, -5.0),
+                Vector::new(-0.5, -1.0, 1.0),
+                4.55006,
+                49.44994,
+            ),
+        ];
+
+        for (origin, direction, t0, t1) in cases {
+            let ray = Ray::new(origin, direction.normalize());
+            let times = cone.times_of_intersections_with(&ray);
+
+            assert_eq!(times.len(), 2);
+            assert!(close_enough(&times[0], &t0));
+            assert!(close_enough(&times[1], &t1));
+        }
+    }
+
+    #[test]
+    fn intersecting_a_cone_with_a_ray_parallel_to_one_of_its_halves() {
+        let cone = Cone::new();
+        let ray = Ray::new(
+            Point::new(0.0, 0.0, -1.0),
+            Vector::new(0.0, 1.0, 1.0).normalize(),
+        );
+
+        let times = cone.times_of_intersections_with(&ray);
+
+        assert_eq!(times.len(), 1);
+        assert!(close_enough(&times[0], &0.35355));
+    }
+
+    #[test]
+    fn a_ray_parallel_to_a_half_through_the_tip_misses() {
+        let cone = Cone::new();
+        let ray = Ray::new(ORIGIN, Vector::new(0.0, 1.0, 1.0).normalize());
+
+        assert!(cone.times_of_intersections_with(&ray).is_empty());
+    }
+
+    #[test]
+    fn intersecting_the_caps_of_a_closed_cone() {
+        let cone = Cone::new_truncated(-0.5, 0.5, true);
+
+        let cases = [
+            (Point::new(0.0, 0.0, -5.0), POSITIVE_Y, 0),
+            (Point::new(0.0, 0.0, -0.25), Vector::new(0.0, 1.0, 1.0), 2),
+            (Point::new(0.0, 0.0, -0.25), POSITIVE_Y, 4),
+        ];
+
+        for (origin, direction, count) in cases {
+            let ray = Ray::new(origin, direction.normalize());
+            assert_eq!(cone.times_of_intersections_with(&ray).len(), count);
+        }
+    }
+
+    #[test]
+    fn computing_the_normal_on_a_cone() {
+        let cone = Cone::new();
+
+        let cases = [
+            (
+                Point::new(1.0, 1.0, 1.0),
+                Vector::new(1.0, -2f64.sqrt(), 1.0),
+            ),
+            (Point::new(-1.0, -1.0, 0.0), Vector::new(-1.0, 1.0, 0.0)),
+        ];
+
+        for (point, expected_normal) in cases {
+            assert_eq!(cone.normal_at(point, None), expected_normal.normalize());
+        }
+    }
+
+    #[test]
+    fn the_normal_on_the_caps_of_a_cone() {
+        let cone = Cone::new_truncated(-1.0, 2.0, true);
+
+        assert_eq!(cone.normal_at(Point::new(0.5, 2.0, 0.0), None), POSITIVE_Y);
+        assert_eq!(cone.normal_at(Point::new(0.0, -1.0, 0.5), None), NEGATIVE_Y);
+    }
+
+    #[test]
+    fn the_bounds_of_a_truncated_cone_fit_its_widest_end() {
+        let bounds = Cone::new_truncated(-5.0, 3.0, false).bounds();
+
+        assert_eq!(*bounds.minimum(), Point::new(-5.0, -5.0, -5.0));
+        assert_eq!(*bounds.maximum(), Point::new(5.0, 3.0, 5.0));
+    }
+}
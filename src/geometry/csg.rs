@@ -0,0 +1,318 @@
+use std::rc::Rc;
+
+use crate::prelude::*;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum CsgOperation {
+    Union,
+    Intersection,
+    Difference,
+}
+
+/// A shape made by combining two others. Like a group, the transform of the CSG shape is applied on
+/// top of the transforms of the two shapes in it.
+pub struct Csg {
+    operation: CsgOperation,
+    left: Rc<dyn Shape>,
+    right: Rc<dyn Shape>,
+    transform: Transform,
+    material: Material,
+    visibility: Visibility,
+}
+
+impl Csg {
+    pub fn new(operation: CsgOperation, left: Rc<dyn Shape>, right: Rc<dyn Shape>) -> Self {
+        Csg {
+            operation,
+            left,
+            right,
+            transform: Transform::new(IDENTITY_MATRIX),
+            material: MaterialBuilder::new().build(),
+            visibility: Visibility::default(),
+        }
+    }
+}
+
+impl Shape for Csg {
+    fn normal_at(&self, _world_space_point: Point, _uv: Option<(f64, f64)>) -> Vector {
+        // Just like with groups, intersections are always with one of the two shapes inside.
+        panic!("A CSG shape doesn't have a normal of its own, ask one of its shapes instead")
+    }
+
+    fn times_of_intersections_with<'s, 'r>(&'s self, ray: &'r Ray) -> Vec<f64>
+    where
+        'r: 's,
+    {
+        self.intersections_with_children(ray, None)
+            .unwrap()
+            .iter()
+            .map(|intersection| *intersection.t())
+            .collect()
+    }
+
+    fn intersections_with_children<'r>(
+        &self,
+        ray: &'r Ray,
+        parent_transform: Option<Transform>,
+    ) -> Option<Vec<Intersection<'r>>> {
+        let transform_for_children = match parent_transform {
+            Some(parent_transform) => parent_transform * self.transform,
+            None => self.transform,
+        };
+
+        if !self.visibility.is_seen_by(ray.kind()) {
+            return Some(Vec::new());
+        }
+
+        // Each intersection is marked with whether it was with the left shape or not.
+        let mut intersections: Vec<(Intersection<'r>, bool)> = Vec::new();
+        for intersection in Intersection::within(&self.left, ray, Some(transform_for_children)) {
+            intersections.push((intersection, true));
+        }
+        for intersection in Intersection::within(&self.right, ray, Some(transform_for_children)) {
+            intersections.push((intersection, false));
+        }
+
+        intersections.sort_by(|(a, _), (b, _)| a.t().total_cmp(b.t()));
+
+        // Going along the ray, every intersection means we've gone either in or out of a shape.
+        let mut in_left = false;
+        let mut in_right = false;
+
+        let mut allowed_intersections = Vec::new();
+        for (intersection, hit_left) in intersections {
+            if intersection_allowed(self.operation, hit_left, in_left, in_right) {
+                allowed_intersections.push(intersection);
+            }
+
+            if hit_left {
+                in_left = !in_left;
+            } else {
+                in_right = !in_right;
+            }
+        }
+
+        Some(allowed_intersections)
+    }
+
+    fn transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    fn set_transform(&mut self, transformation: Transform) {
+        self.transform = transformation;
+    }
+
+    fn material(&self) -> &Material {
+        &self.material
+    }
+
+    /// Like with groups, rays only ever hit the shapes inside, which keep their own materials.
+    fn set_material(&mut self, material: Material) {
+        self.material = material;
+    }
+
+    fn visibility(&self) -> &Visibility {
+        &self.visibility
+    }
+
+    /// Like with groups, this hides both shapes inside on top of what they hide themselves from.
+    fn set_visibility(&mut self, visibility: Visibility) {
+        self.visibility = visibility;
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        let mut bounds = self.left.parent_space_bounds();
+        bounds.add_box(&self.right.parent_space_bounds());
+
+        bounds
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::Csg
+    }
+}
+
+// Decides if an intersection with one of the shapes is part of the surface of the combined shape,
+// given which shape was hit and whether we're currently inside of each of them.
+fn intersection_allowed(
+    operation: CsgOperation,
+    hit_left: bool,
+    in_left: bool,
+    in_right: bool,
+) -> bool {
+    match operation {
+        CsgOperation::Union => (hit_left && !in_right) || (!hit_left && !in_left),
+        CsgOperation::Intersection => (hit_left && in_right) || (!hit_left && in_left),
+        CsgOperation::Difference => (hit_left && !in_right) || (!hit_left && in_left),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn evaluating_the_rule_for_each_operation() {
+        // Each case is the operation, whether the left shape was hit, whether we're inside the
+        // left shape, whether we're inside the right shape, and the expected result.
+        let cases = [
+            (CsgOperation::Union, true, true, true, false),
+            (CsgOperation::Union, true, true, false, true),
+            (CsgOperation::Union, true, false, true, false),
+            (CsgOperation::Union, true, false, false, true),
+            (CsgOperation::Union, false, true, true, false),
+            (CsgOperation::Union, false, true, false, false),
+            (CsgOperation::Union, false, false, true, true),
+            (CsgOperation::Union, false, false, false, true),
+            (CsgOperation::Intersection, true, true, true, true),
+            (CsgOperation::Intersection, true, true, false, false),
+            (CsgOperation::Intersection, true, false, true, true),
+            (CsgOperation::Intersection, true, false, false, false),
+            (CsgOperation::Intersection, false, true, true, true),
+            (CsgOperation::Intersection, false, true, false, true),
+            (CsgOperation::Intersection, false, false, true, false),
+            (CsgOperation::Intersection, false, false, false, false),
+            (CsgOperation::Difference, true, true, true, false),
+            (CsgOperation::Difference, true, true, false, true),
+            (CsgOperation::Difference, true, false, true, false),
+            (CsgOperation::Difference, true, false, false, true),
+            (CsgOperation::Difference, false, true, true, true),
+            (CsgOperation::Difference, false, true, false, true),
+            (CsgOperation::Difference, false, false, true, false),
+            (CsgOperation::Difference, false, false, false, false),
+        ];
+
+        for (operation, hit_left, in_left, in_right, expected) in cases {
+            assert_eq!(
+                intersection_allowed(operation, hit_left, in_left, in_right),
+                expected,
+                "{:?} with hit_left: {}, in_left: {}, in_right: {}",
+                operation,
+                hit_left,
+                in_left,
+                in_right
+            );
+        }
+    }
+
+    #[test]
+    fn filtering_intersections_for_each_operation() {
+        let sphere: Rc<dyn Shape> = Rc::new(Sphere::new());
+        let cube: Rc<dyn Shape> = Rc::new(Cube::new());
+
+        // The sphere and cube overlap completely, except that the cube sticks out of the sphere at
+        // its corners. A ray through a corner hits the cube first and last.
+        let ray = Ray::new(
+            Point::new(-2.0, -2.0, -2.0),
+            Vector::new(1.0, 1.0, 1.0).normalize(),
+        );
+
+        let cube_enters = 3.0_f64.sqrt();
+        let sphere_enters = 2.0 * 3.0_f64.sqrt() - 1.0;
+        let sphere_leaves = 2.0 * 3.0_f64.sqrt() + 1.0;
+        let cube_leaves = 3.0 * 3.0_f64.sqrt();
+
+        let cases = [
+            (CsgOperation::Union, vec![cube_enters, cube_leaves]),
+            (
+                CsgOperation::Intersection,
+                vec![sphere_enters, sphere_leaves],
+            ),
+            (CsgOperation::Difference, vec![]),
+        ];
+
+        for (operation, expected_times) in cases {
+            let csg = Csg::new(operation, sphere.clone(), cube.clone());
+            let times = csg.times_of_intersections_with(&ray);
+
+            assert_eq!(times.len(), expected_times.len(), "{:?}", operation);
+            for (time, expected_time) in times.iter().zip(expected_times.iter()) {
+                assert!(close_enough(time, expected_time), "{:?}", operation);
+            }
+        }
+    }
+
+    #[test]
+    fn a_ray_misses_a_csg_shape() {
+        let csg = Csg::new(
+            CsgOperation::Union,
+            Rc::new(Sphere::new()),
+            Rc::new(Cube::new()),
+        );
+        let ray = Ray::new(Point::new(0.0, 2.0, -5.0), POSITIVE_Z);
+
+        assert!(csg.times_of_intersections_with(&ray).is_empty());
+    }
+
+    #[test]
+    fn a_ray_hits_a_csg_shape_and_the_intersections_are_with_its_shapes() {
+        let first_sphere: Rc<dyn Shape> = Rc::new(Sphere::new());
+
+        let mut second_sphere = Sphere::new();
+        second_sphere.set_transform(Transform::translation(0.0, 0.0, 0.5));
+        let second_sphere: Rc<dyn Shape> = Rc::new(second_sphere);
+
+        let csg: Rc<dyn Shape> = Rc::new(Csg::new(
+            CsgOperation::Union,
+            first_sphere.clone(),
+            second_sphere.clone(),
+        ));
+
+        let ray = Ray::new(Point::new(0.0, 0.0, -5.0), POSITIVE_Z);
+        let intersections = Intersection::of(&csg, &ray);
+
+        assert_eq!(intersections.len(), 2);
+        assert_eq!(*intersections[0].t(), 4.0);
+        assert!(Rc::ptr_eq(intersections[0].object(), &first_sphere));
+        assert_eq!(*intersections[1].t(), 6.5);
+        assert!(Rc::ptr_eq(intersections[1].object(), &second_sphere));
+    }
+
+    #[test]
+    fn a_lens_made_from_two_spheres_has_the_right_normals() {
+        let mut left = Sphere::new();
+        left.set_transform(Transform::translation(0.0, 0.0, 0.5));
+
+        let mut right = Sphere::new();
+        right.set_transform(Transform::translation(0.0, 0.0, -0.5));
+
+        let mut lens = Csg::new(CsgOperation::Intersection, Rc::new(left), Rc::new(right));
+        lens.set_transform(Transform::scaling(2.0, 2.0, 2.0));
+        let lens: Rc<dyn Shape> = Rc::new(lens);
+
+        let ray = Ray::new(Point::new(0.0, 0.0, -5.0), POSITIVE_Z);
+        let hit = determine_hit(Intersection::of(&lens, &ray)).unwrap();
+
+        // The front of the lens is the front of the sphere that was moved back.
+        assert_eq!(hit.point(), Point::new(0.0, 0.0, -1.0));
+        assert_eq!(hit.normal_vector(), NEGATIVE_Z);
+    }
+
+    #[test]
+    fn the_bounds_of_a_csg_shape_fit_both_of_its_shapes() {
+        let mut cube = Cube::new();
+        cube.set_transform(Transform::translation(2.0, 3.0, 4.0));
+
+        let csg = Csg::new(
+            CsgOperation::Difference,
+            Rc::new(Sphere::new()),
+            Rc::new(cube),
+        );
+
+        let bounds = csg.bounds();
+
+        assert_eq!(*bounds.minimum(), Point::new(-1.0, -1.0, -1.0));
+        assert_eq!(*bounds.maximum(), Point::new(3.0, 4.0, 5.0));
+    }
+
+    #[test]
+    fn the_same_shape_can_be_used_on_both_sides() {
+        let sphere: Rc<dyn Shape> = Rc::new(Sphere::new());
+
+        let mut csg = Csg::new(CsgOperation::Union, sphere.clone(), sphere.clone());
+        csg.set_material(MaterialBuilder::new().flat_color(RED).build());
+
+        assert_eq!(*sphere.material(), MaterialBuilder::new().build());
+    }
+}
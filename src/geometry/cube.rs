@@ -0,0 +1,214 @@
+use crate::prelude::*;
+
+#[derive(Debug, PartialEq)]
+pub struct Cube {
+    transform: Transform,
+    material: Material,
+    visibility: Visibility,
+}
+
+impl Cube {
+    pub fn new() -> Self {
+        Cube {
+            transform: Transform::new(IDENTITY_MATRIX),
+            material: MaterialBuilder::new().build(),
+            visibility: Visibility::default(),
+        }
+    }
+}
+
+impl Shape for Cube {
+    fn normal_at(&self, world_space_point: Point, _uv: Option<(f64, f64)>) -> Vector {
+        let transform_inverse = self.transform.invert().unwrap();
+        let object_space_point = transform_inverse * world_space_point;
+
+        let x = object_space_point.x().abs();
+        let y = object_space_point.y().abs();
+        let z = object_space_point.z().abs();
+
+        // The face that was hit is the one whose axis has the largest absolute component.
+        let object_space_normal = if x >= y && x >= z {
+            Vector::new(*object_space_point.x(), 0.0, 0.0)
+        } else if y >= z {
+            Vector::new(0.0, *object_space_point.y(), 0.0)
+        } else {
+            Vector::new(0.0, 0.0, *object_space_point.z())
+        };
+
+        let world_space_normal = transform_inverse.matrix().transpose() * object_space_normal;
+
+        world_space_normal.normalize()
+    }
+
+    fn times_of_intersections_with<'s, 'r>(&'s self, ray: &'r Ray) -> Vec<f64>
+    where
+        'r: 's,
+    {
+        let local_ray = self.transform.invert().unwrap() * ray;
+        let origin = local_ray.origin();
+        let direction = local_ray.direction();
+
+        let (x_min, x_max) = check_axis(*origin.x(), *direction.x());
+        let (y_min, y_max) = check_axis(*origin.y(), *direction.y());
+        let (z_min, z_max) = check_axis(*origin.z(), *direction.z());
+
+        let t_min = x_min.max(y_min).max(z_min);
+        let t_max = x_max.min(y_max).min(z_max);
+
+        if t_min > t_max {
+            return vec![];
+        }
+
+        vec![t_min, t_max]
+    }
+
+    fn transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    fn set_transform(&mut self, transformation: Transform) {
+        self.transform = transformation;
+    }
+
+    fn material(&self) -> &Material {
+        &self.material
+    }
+
+    fn set_material(&mut self, material: Material) {
+        self.material = material;
+    }
+
+    fn visibility(&self) -> &Visibility {
+        &self.visibility
+    }
+
+    fn set_visibility(&mut self, visibility: Visibility) {
+        self.visibility = visibility;
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        BoundingBox::new(Point::new(-1.0, -1.0, -1.0), Point::new(1.0, 1.0, 1.0))
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::Cube
+    }
+}
+
+// Finds the times at which a ray crosses the two planes of the "slab" for one axis of the cube.
+fn check_axis(origin: f64, direction: f64) -> (f64, f64) {
+    let t_min_numerator = -1.0 - origin;
+    let t_max_numerator = 1.0 - origin;
+
+    // Dividing by zero gives us infinities with the right signs, which is what we want when the
+    // ray is parallel to the slab.
+    let t_min = t_min_numerator / direction;
+    let t_max = t_max_numerator / direction;
+
+    if t_min > t_max {
+        (t_max, t_min)
+    } else {
+        (t_min, t_max)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn a_ray_intersects_each_face_of_a_cube() {
+        let cube = Cube::new();
+
+        let cases = [
+            (Point::new(5.0, 0.5, 0.0), NEGATIVE_X, 4.0, 6.0),
+            (Point::new(-5.0, 0.5, 0.0), POSITIVE_X, 4.0, 6.0),
+            (Point::new(0.5, 5.0, 0.0), NEGATIVE_Y, 4.0, 6.0),
+            (Point::new(0.5, -5.0, 0.0), POSITIVE_Y, 4.0, 6.0),
+            (Point::new(0.5, 0.0, 5.0), NEGATIVE_Z, 4.0, 6.0),
+            (Point::new(0.5, 0.0, -5.0), POSITIVE_Z, 4.0, 6.0),
+            (Point::new(0.0, 0.5, 0.0), POSITIVE_Z, -1.0, 1.0),
+        ];
+
+        for (origin, direction, t1, t2) in cases {
+            let times = cube.times_of_intersections_with(&Ray::new(origin, direction));
+
+            assert_eq!(times.len(), 2);
+            assert_eq!(times[0], t1);
+            assert_eq!(times[1], t2);
+        }
+    }
+
+    #[test]
+    fn a_ray_misses_a_cube() {
+        let cube = Cube::new();
+
+        let cases = [
+            (
+                Point::new(-2.0, 0.0, 0.0),
+                Vector::new(0.2673, 0.5345, 0.8018),
+            ),
+            (
+                Point::new(0.0, -2.0, 0.0),
+                Vector::new(0.8018, 0.2673, 0.5345),
+            ),
+            (
+                Point::new(0.0, 0.0, -2.0),
+                Vector::new(0.5345, 0.8018, 0.2673),
+            ),
+            (Point::new(2.0, 0.0, 2.0), NEGATIVE_Z),
+            (Point::new(0.0, 2.0, 2.0), NEGATIVE_Y),
+            (Point::new(2.0, 2.0, 0.0), NEGATIVE_X),
+        ];
+
+        for (origin, direction) in cases {
+            let times = cube.times_of_intersections_with(&Ray::new(origin, direction));
+
+            assert!(times.is_empty());
+        }
+    }
+
+    #[test]
+    fn the_normal_on_the_surface_of_a_cube_points_out_of_the_face() {
+        let cube = Cube::new();
+
+        let cases = [
+            (Point::new(1.0, 0.5, -0.8), POSITIVE_X),
+            (Point::new(-1.0, -0.2, 0.9), NEGATIVE_X),
+            (Point::new(-0.4, 1.0, -0.1), POSITIVE_Y),
+            (Point::new(0.3, -1.0, -0.7), NEGATIVE_Y),
+            (Point::new(-0.6, 0.3, 1.0), POSITIVE_Z),
+            (Point::new(0.4, 0.4, -1.0), NEGATIVE_Z),
+        ];
+
+        for (point, expected_normal) in cases {
+            assert_eq!(cube.normal_at(point, None), expected_normal);
+        }
+    }
+
+    #[test]
+    fn the_normal_at_a_corner_of_a_cube_points_along_the_x_axis() {
+        let cube = Cube::new();
+
+        assert_eq!(cube.normal_at(Point::new(1.0, 1.0, 1.0), None), POSITIVE_X);
+        assert_eq!(
+            cube.normal_at(Point::new(-1.0, -1.0, -1.0), None),
+            NEGATIVE_X
+        );
+    }
+
+    #[test]
+    fn intersecting_a_translated_and_scaled_cube() {
+        let mut cube = Cube::new();
+        cube.set_transform(
+            Transform::translation(0.0, 0.0, 5.0) * Transform::scaling(2.0, 2.0, 2.0),
+        );
+
+        let ray = Ray::new(ORIGIN, POSITIVE_Z);
+        let times = cube.times_of_intersections_with(&ray);
+
+        assert_eq!(times.len(), 2);
+        assert_eq!(times[0], 3.0);
+        assert_eq!(times[1], 7.0);
+    }
+}
@@ -0,0 +1,289 @@
+use crate::prelude::*;
+
+#[derive(Debug, PartialEq)]
+pub struct Cylinder {
+    minimum: f64,
+    maximum: f64,
+    closed: bool,
+    transform: Transform,
+    material: Material,
+    visibility: Visibility,
+}
+
+impl Cylinder {
+    pub fn new() -> Self {
+        Cylinder {
+            minimum: f64::NEG_INFINITY,
+            maximum: f64::INFINITY,
+            closed: false,
+            transform: Transform::new(IDENTITY_MATRIX),
+            material: MaterialBuilder::new().build(),
+            visibility: Visibility::default(),
+        }
+    }
+
+    /// Creates a cylinder that only extends between `minimum` and `maximum` along the y axis in
+    /// object space (exclusive of the limits themselves). If `closed` is true, the ends are capped.
+    pub fn new_truncated(minimum: f64, maximum: f64, closed: bool) -> Self {
+        Cylinder {
+            minimum,
+            maximum,
+            closed,
+            ..Cylinder::new()
+        }
+    }
+
+    fn intersect_caps(&self, ray: &Ray, times: &mut Vec<f64>) {
+        if !self.closed || close_enough(ray.direction().y(), &0.0) {
+            return;
+        }
+
+        for cap in [self.minimum, self.maximum] {
+            let t = (cap - ray.origin().y()) / ray.direction().y();
+            if is_within_radius(ray, t) {
+                times.push(t);
+            }
+        }
+    }
+}
+
+impl Shape for Cylinder {
+    fn normal_at(&self, world_space_point: Point, _uv: Option<(f64, f64)>) -> Vector {
+        let transform_inverse = self.transform.invert().unwrap();
+        let object_space_point = transform_inverse * world_space_point;
+
+        let x = *object_space_point.x();
+        let y = *object_space_point.y();
+        let z = *object_space_point.z();
+
+        let distance_from_y_axis = x.powi(2) + z.powi(2);
+
+        let object_space_normal = if distance_from_y_axis < 1.0 && y >= self.maximum - EPSILON {
+            POSITIVE_Y
+        } else if distance_from_y_axis < 1.0 && y <= self.minimum + EPSILON {
+            NEGATIVE_Y
+        } else {
+            Vector::new(x, 0.0, z)
+        };
+
+        let world_space_normal = transform_inverse.matrix().transpose() * object_space_normal;
+
+        world_space_normal.normalize()
+    }
+
+    fn times_of_intersections_with<'s, 'r>(&'s self, ray: &'r Ray) -> Vec<f64>
+    where
+        'r: 's,
+    {
+        let local_ray = self.transform.invert().unwrap() * ray;
+        let origin = local_ray.origin();
+        let direction = local_ray.direction();
+
+        let mut times = Vec::new();
+
+        let a = direction.x().powi(2) + direction.z().powi(2);
+
+        // If the ray is parallel to the y axis it can't hit the walls, but it might hit the caps.
+        if !close_enough(&a, &0.0) {
+            let b = 2.0 * origin.x() * direction.x() + 2.0 * origin.z() * direction.z();
+            let c = origin.x().powi(2) + origin.z().powi(2) - 1.0;
+
+            let discriminant = b.powi(2) - 4.0 * a * c;
+
+            if discriminant < 0.0 {
+                return times;
+            }
+
+            let t0 = (-b - discriminant.sqrt()) / (2.0 * a);
+            let t1 = (-b + discriminant.sqrt()) / (2.0 * a);
+
+            for t in [t0, t1] {
+                let y = origin.y() + t * direction.y();
+                if self.minimum < y && y < self.maximum {
+                    times.push(t);
+                }
+            }
+        }
+
+        self.intersect_caps(&local_ray, &mut times);
+
+        times
+    }
+
+    fn transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    fn set_transform(&mut self, transformation: Transform) {
+        self.transform = transformation;
+    }
+
+    fn material(&self) -> &Material {
+        &self.material
+    }
+
+    fn set_material(&mut self, material: Material) {
+        self.material = material;
+    }
+
+    fn visibility(&self) -> &Visibility {
+        &self.visibility
+    }
+
+    fn set_visibility(&mut self, visibility: Visibility) {
+        self.visibility = visibility;
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        BoundingBox::new(
+            Point::new(-1.0, self.minimum, -1.0),
+            Point::new(1.0, self.maximum, 1.0),
+        )
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::Cylinder
+    }
+}
+
+// Checks if the point on the ray at time `t` is within the radius of the cylinder (which is 1).
+fn is_within_radius(ray: &Ray, t: f64) -> bool {
+    let x = ray.origin().x() + t * ray.direction().x();
+    let z = ray.origin().z() + t * ray.direction().z();
+
+    x.powi(2) + z.powi(2) <= 1.0
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn a_ray_misses_a_cylinder() {
+        let cylinder = Cylinder::new();
+
+        let cases = [
+            (Point::new(1.0, 0.0, 0.0), POSITIVE_Y),
+            (ORIGIN, POSITIVE_Y),
+            (Point::new(0.0, 0.0, -5.0), Vector::new(1.0, 1.0, 1.0)),
+        ];
+
+        for (origin, direction) in cases {
+            let ray = Ray::new(origin, direction.normalize());
+            assert!(cylinder.times_of_intersections_with(&ray).is_empty());
+        }
+    }
+
+    #[test]
+    fn a_ray_strikes_a_cylinder() {
+        let cylinder = Cylinder::new();
+
+        let cases = [
+            (Point::new(1.0, 0.0, -5.0), POSITIVE_Z, 5.0, 5.0),
+            (Point::new(0.0, 0.0, -5.0), POSITIVE_Z, 4.0, 6.0),
+            (
+                Point::new(0.5, 0.0, -5.0),
+                Vector::new(0.1, 1.0, 1.0),
+                6.80798,
+                7.08872,
+            ),
+        ];
+
+        for (origin, direction, t0, t1) in cases {
+            let ray = Ray::new(origin, direction.normalize());
+            let times = cylinder.times_of_intersections_with(&ray);
+
+            assert_eq!(times.len(), 2);
+            assert!(close_enough(&times[0], &t0));
+            assert!(close_enough(&times[1], &t1));
+        }
+    }
+
+    #[test]
+    fn the_normal_on_a_cylinder_points_away_from_the_y_axis() {
+        let cylinder = Cylinder::new();
+
+        let cases = [
+            (Point::new(1.0, 0.0, 0.0), POSITIVE_X),
+            (Point::new(0.0, 5.0, -1.0), NEGATIVE_Z),
+            (Point::new(0.0, -2.0, 1.0), POSITIVE_Z),
+            (Point::new(-1.0, 1.0, 0.0), NEGATIVE_X),
+        ];
+
+        for (point, expected_normal) in cases {
+            assert_eq!(cylinder.normal_at(point, None), expected_normal);
+        }
+    }
+
+    #[test]
+    fn a_default_cylinder_is_infinite_and_open() {
+        let cylinder = Cylinder::new();
+
+        assert_eq!(cylinder.minimum, f64::NEG_INFINITY);
+        assert_eq!(cylinder.maximum, f64::INFINITY);
+        assert!(!cylinder.closed);
+    }
+
+    #[test]
+    fn intersecting_a_truncated_cylinder() {
+        let cylinder = Cylinder::new_truncated(1.0, 2.0, false);
+
+        let cases = [
+            (Point::new(0.0, 1.5, 0.0), Vector::new(0.1, 1.0, 0.0), 0),
+            (Point::new(0.0, 3.0, -5.0), POSITIVE_Z, 0),
+            (Point::new(0.0, 0.0, -5.0), POSITIVE_Z, 0),
+            (Point::new(0.0, 2.0, -5.0), POSITIVE_Z, 0),
+            (Point::new(0.0, 1.0, -5.0), POSITIVE_Z, 0),
+            (Point::new(0.0, 1.5, -2.0), POSITIVE_Z, 2),
+        ];
+
+        for (origin, direction, count) in cases {
+            let ray = Ray::new(origin, direction.normalize());
+            assert_eq!(cylinder.times_of_intersections_with(&ray).len(), count);
+        }
+    }
+
+    #[test]
+    fn intersecting_the_caps_of_a_closed_cylinder() {
+        let cylinder = Cylinder::new_truncated(1.0, 2.0, true);
+
+        let cases = [
+            (Point::new(0.0, 3.0, 0.0), NEGATIVE_Y, 2),
+            (Point::new(0.0, 3.0, -2.0), Vector::new(0.0, -1.0, 2.0), 2),
+            (Point::new(0.0, 4.0, -2.0), Vector::new(0.0, -1.0, 1.0), 2),
+            (Point::new(0.0, 0.0, -2.0), Vector::new(0.0, 1.0, 2.0), 2),
+            (Point::new(0.0, -1.0, -2.0), Vector::new(0.0, 1.0, 1.0), 2),
+        ];
+
+        for (origin, direction, count) in cases {
+            let ray = Ray::new(origin, direction.normalize());
+            assert_eq!(cylinder.times_of_intersections_with(&ray).len(), count);
+        }
+    }
+
+    #[test]
+    fn the_normal_on_the_caps_of_a_cylinder() {
+        let cylinder = Cylinder::new_truncated(1.0, 2.0, true);
+
+        let cases = [
+            (Point::new(0.0, 1.0, 0.0), NEGATIVE_Y),
+            (Point::new(0.5, 1.0, 0.0), NEGATIVE_Y),
+            (Point::new(0.0, 1.0, 0.5), NEGATIVE_Y),
+            (Point::new(0.0, 2.0, 0.0), POSITIVE_Y),
+            (Point::new(0.5, 2.0, 0.0), POSITIVE_Y),
+            (Point::new(0.0, 2.0, 0.5), POSITIVE_Y),
+        ];
+
+        for (point, expected_normal) in cases {
+            assert_eq!(cylinder.normal_at(point, None), expected_normal);
+        }
+    }
+
+    #[test]
+    fn the_bounds_of_a_truncated_cylinder() {
+        let bounds = Cylinder::new_truncated(-5.0, 3.0, true).bounds();
+
+        assert_eq!(*bounds.minimum(), Point::new(-1.0, -5.0, -1.0));
+        assert_eq!(*bounds.maximum(), Point::new(1.0, 3.0, 1.0));
+    }
+}
@@ -0,0 +1,284 @@
+use std::{cell::OnceCell, rc::Rc};
+
+use crate::prelude::*;
+
+/// A collection of shapes that can be transformed together. The transform of the group is applied
+/// on top of the transforms of each of its children.
+pub struct Group {
+    children: Vec<Rc<dyn Shape>>,
+    transform: Transform,
+    material: Material,
+    visibility: Visibility,
+    // Built the first time a ray is checked against the group, and thrown away if it changes.
+    bvh: OnceCell<Bvh>,
+}
+
+impl Group {
+    pub fn new() -> Self {
+        Group {
+            children: Vec::new(),
+            transform: Transform::new(IDENTITY_MATRIX),
+            material: MaterialBuilder::new().build(),
+            visibility: Visibility::default(),
+            bvh: OnceCell::new(),
+        }
+    }
+
+    pub fn add_child(&mut self, child: Rc<dyn Shape>) {
+        self.bvh = OnceCell::new();
+        self.children.push(child);
+    }
+
+    pub fn children(&self) -> &Vec<Rc<dyn Shape>> {
+        &self.children
+    }
+
+    fn bvh(&self) -> &Bvh {
+        self.bvh.get_or_init(|| Bvh::new(&self.children))
+    }
+}
+
+impl Shape for Group {
+    fn normal_at(&self, _world_space_point: Point, _uv: Option<(f64, f64)>) -> Vector {
+        // Intersections are always with the children of a group, never the group itself, so this
+        // should never be asked for.
+        panic!("A group doesn't have a normal of its own, ask one of its children instead")
+    }
+
+    fn times_of_intersections_with<'s, 'r>(&'s self, ray: &'r Ray) -> Vec<f64>
+    where
+        'r: 's,
+    {
+        let local_ray = self.transform.invert().unwrap() * ray;
+
+        let mut times = Vec::new();
+        for child in self.bvh().shapes_along(&local_ray) {
+            times.append(&mut child.times_of_intersections_with(&local_ray));
+        }
+
+        times
+    }
+
+    fn intersections_with_children<'r>(
+        &self,
+        ray: &'r Ray,
+        parent_transform: Option<Transform>,
+    ) -> Option<Vec<Intersection<'r>>> {
+        let transform_for_children = match parent_transform {
+            Some(parent_transform) => parent_transform * self.transform,
+            None => self.transform,
+        };
+
+        if !self.visibility.is_seen_by(ray.kind()) {
+            return Some(Vec::new());
+        }
+
+        let ray_in_group_space = transform_for_children.invert().unwrap() * ray;
+
+        let mut intersections = Vec::new();
+        for child in self.bvh().shapes_along(&ray_in_group_space) {
+            intersections.append(&mut Intersection::within(
+                child,
+                ray,
+                Some(transform_for_children),
+            ));
+        }
+
+        Some(intersections)
+    }
+
+    fn transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    fn set_transform(&mut self, transformation: Transform) {
+        self.transform = transformation;
+    }
+
+    fn material(&self) -> &Material {
+        &self.material
+    }
+
+    /// Rays only ever hit the children of a group, which keep their own materials. The children
+    /// need to be given this material before they're added to the group to look the same.
+    fn set_material(&mut self, material: Material) {
+        self.material = material;
+    }
+
+    fn visibility(&self) -> &Visibility {
+        &self.visibility
+    }
+
+    /// Hides everything in the group from the kinds of rays it's hidden from, on top of whatever
+    /// the children hide themselves from.
+    fn set_visibility(&mut self, visibility: Visibility) {
+        self.visibility = visibility;
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        self.bvh().bounds()
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::Group
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn a_new_group_is_empty() {
+        let group = Group::new();
+
+        assert!(group.children().is_empty());
+        assert_eq!(*group.transform(), IDENTITY_MATRIX);
+    }
+
+    #[test]
+    fn intersecting_a_ray_with_an_empty_group() {
+        let group: Rc<dyn Shape> = Rc::new(Group::new());
+        let ray = Ray::new(ORIGIN, POSITIVE_Z);
+
+        assert!(Intersection::of(&group, &ray).is_empty());
+    }
+
+    #[test]
+    fn intersecting_a_ray_with_a_group_gives_intersections_with_its_children() {
+        let first_sphere: Rc<dyn Shape> = Rc::new(Sphere::new());
+
+        let mut second_sphere = Sphere::new();
+        second_sphere.set_transform(Transform::translation(0.0, 0.0, -3.0));
+        let second_sphere: Rc<dyn Shape> = Rc::new(second_sphere);
+
+        let mut third_sphere = Sphere::new();
+        third_sphere.set_transform(Transform::translation(5.0, 0.0, 0.0));
+        let third_sphere: Rc<dyn Shape> = Rc::new(third_sphere);
+
+        let mut group = Group::new();
+        group.add_child(first_sphere.clone());
+        group.add_child(second_sphere.clone());
+        group.add_child(third_sphere);
+        let group: Rc<dyn Shape> = Rc::new(group);
+
+        let ray = Ray::new(Point::new(0.0, 0.0, -5.0), POSITIVE_Z);
+        let mut intersections = Intersection::of(&group, &ray);
+        intersections.sort_by(|a, b| a.t().total_cmp(b.t()));
+
+        assert_eq!(intersections.len(), 4);
+        assert!(Rc::ptr_eq(intersections[0].object(), &second_sphere));
+        assert!(Rc::ptr_eq(intersections[1].object(), &second_sphere));
+        assert!(Rc::ptr_eq(intersections[2].object(), &first_sphere));
+        assert!(Rc::ptr_eq(intersections[3].object(), &first_sphere));
+    }
+
+    #[test]
+    fn intersecting_a_transformed_group() {
+        let mut sphere = Sphere::new();
+        sphere.set_transform(Transform::translation(5.0, 0.0, 0.0));
+
+        let mut group = Group::new();
+        group.set_transform(Transform::scaling(2.0, 2.0, 2.0));
+        group.add_child(Rc::new(sphere));
+        let group: Rc<dyn Shape> = Rc::new(group);
+
+        let ray = Ray::new(Point::new(10.0, 0.0, -10.0), POSITIVE_Z);
+
+        assert_eq!(Intersection::of(&group, &ray).len(), 2);
+    }
+
+    #[test]
+    fn finding_the_normal_on_a_child_of_nested_groups() {
+        let mut sphere = Sphere::new();
+        sphere.set_transform(Transform::translation(5.0, 0.0, 0.0));
+
+        let mut inner_group = Group::new();
+        inner_group.set_transform(Transform::scaling(1.0, 2.0, 3.0));
+        inner_group.add_child(Rc::new(sphere));
+
+        let mut outer_group = Group::new();
+        outer_group.set_transform(Transform::y_rotation(PI / 2.0));
+        outer_group.add_child(Rc::new(inner_group));
+        let outer_group: Rc<dyn Shape> = Rc::new(outer_group);
+
+        // This is where (0, 0.6, -0.8) on the surface of the sphere ends up after all the
+        // transformations, and the ray heads straight back to it along its normal.
+        let point = Point::new(-2.4, 1.2, -5.0);
+        let expected_normal = Vector::new(-0.8 / 3.0, 0.3, 0.0).normalize();
+        let ray = Ray::new(point + expected_normal * 2.0, -expected_normal);
+
+        let intersections = Intersection::of(&outer_group, &ray);
+        let hit = determine_hit(intersections).unwrap();
+
+        assert_eq!(hit.point(), point);
+        assert_eq!(hit.normal_vector(), expected_normal);
+    }
+
+    #[test]
+    fn converting_a_point_into_the_space_of_a_child_of_nested_groups() {
+        let mut sphere = Sphere::new();
+        sphere.set_transform(Transform::translation(5.0, 0.0, 0.0));
+
+        let mut inner_group = Group::new();
+        inner_group.set_transform(Transform::scaling(2.0, 2.0, 2.0));
+        inner_group.add_child(Rc::new(sphere));
+
+        let mut outer_group = Group::new();
+        outer_group.set_transform(Transform::y_rotation(PI / 2.0));
+        outer_group.add_child(Rc::new(inner_group));
+        let outer_group: Rc<dyn Shape> = Rc::new(outer_group);
+
+        // A ray going straight through the middle of the sphere.
+        let ray = Ray::new(Point::new(-20.0, 0.0, -10.0), POSITIVE_X);
+        let intersections = Intersection::of(&outer_group, &ray);
+        let hit = determine_hit(intersections).unwrap();
+
+        assert_eq!(
+            hit.to_object_space(Point::new(-2.0, 0.0, -10.0)),
+            Point::new(0.0, 0.0, -1.0)
+        );
+    }
+
+    #[test]
+    fn children_can_be_shared_between_groups_and_keep_their_own_material() {
+        let material = MaterialBuilder::new().flat_color(RED).ambient(0.5).build();
+
+        let mut sphere = Sphere::new();
+        sphere.set_material(material.clone());
+        let sphere: Rc<dyn Shape> = Rc::new(sphere);
+
+        let mut first_group = Group::new();
+        first_group.add_child(sphere.clone());
+        let mut second_group = Group::new();
+        second_group.add_child(sphere.clone());
+
+        second_group.set_material(MaterialBuilder::new().flat_color(WHITE).build());
+
+        assert_eq!(*first_group.children()[0].material(), material);
+        assert_eq!(*second_group.children()[0].material(), material);
+    }
+
+    #[test]
+    fn the_bounds_of_a_group_fit_all_of_its_transformed_children() {
+        let mut sphere = Sphere::new();
+        sphere.set_transform(
+            Transform::translation(2.0, 5.0, -3.0) * Transform::scaling(2.0, 2.0, 2.0),
+        );
+
+        let mut cylinder = Cylinder::new_truncated(-2.0, 2.0, false);
+        cylinder.set_transform(
+            Transform::translation(-4.0, -1.0, 4.0) * Transform::scaling(0.5, 1.0, 0.5),
+        );
+
+        let mut group = Group::new();
+        group.add_child(Rc::new(sphere));
+        group.add_child(Rc::new(cylinder));
+
+        let bounds = group.bounds();
+
+        assert_eq!(*bounds.minimum(), Point::new(-4.5, -3.0, -5.0));
+        assert_eq!(*bounds.maximum(), Point::new(4.0, 7.0, 4.5));
+    }
+}
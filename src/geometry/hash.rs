@@ -0,0 +1,44 @@
+/// Combines the values into a number that looks random, but is always the same for the same
+/// values. Changing any of them changes the whole thing.
+pub fn hash_values(values: &[u64]) -> u64 {
+    values.iter().fold(0, |hash, value| split_mix(hash ^ value))
+}
+
+/// Turns a hash into a number between 0 (included) and 1 (not included).
+pub fn hash_to_unit(hash: u64) -> f64 {
+    // The top 53 bits are as many as fit in the fraction of an f64.
+    (hash >> 11) as f64 / (1_u64 << 53) as f64
+}
+
+// A step of SplitMix64: the value is moved along by its constant, and then run through its
+// finalizer, which spreads every bit of the input over the whole output.
+fn split_mix(value: u64) -> u64 {
+    let mut value = value.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    value ^ (value >> 31)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn the_same_values_always_give_the_same_hash() {
+        assert_eq!(hash_values(&[1, 2, 3]), hash_values(&[1, 2, 3]));
+        assert_ne!(hash_values(&[1, 2, 3]), hash_values(&[1, 2, 4]));
+        assert_ne!(hash_values(&[1, 2, 3]), hash_values(&[3, 2, 1]));
+        assert_ne!(hash_values(&[0]), 0);
+    }
+
+    #[test]
+    fn hashes_turn_into_numbers_from_zero_up_to_one() {
+        assert_eq!(hash_to_unit(0), 0.0);
+        assert!(hash_to_unit(u64::MAX) < 1.0);
+
+        for i in 0..100 {
+            let value = hash_to_unit(hash_values(&[i]));
+            assert!((0.0..1.0).contains(&value));
+        }
+    }
+}
@@ -7,6 +7,9 @@ pub struct Intersection<'r> {
     time: f64,
     object: Rc<dyn Shape>,
     ray: &'r Ray,
+    uv: Option<(f64, f64)>,
+    // The combined transform of all of the groups the object is in, if it's in any.
+    parent_transform: Option<Transform>,
 }
 
 impl<'r> Eq for Intersection<'r> {}
@@ -19,16 +22,45 @@ impl<'r> PartialEq for Intersection<'r> {
 
 impl<'r> Intersection<'r> {
     fn new(time: f64, object: Rc<dyn Shape>, ray: &'r Ray) -> Self {
-        Intersection { time, object, ray }
+        Intersection {
+            time,
+            object,
+            ray,
+            uv: None,
+            parent_transform: None,
+        }
     }
 
     pub fn of(object: &Rc<dyn Shape>, ray: &'r Ray) -> Vec<Self> {
-        let mut intersections = Vec::new();
+        Intersection::within(object, ray, None)
+    }
+
+    /// Finds the intersections of the ray with an object that is inside of groups, whose combined
+    /// transform is the `parent_transform`.
+    pub fn within(
+        object: &Rc<dyn Shape>,
+        ray: &'r Ray,
+        parent_transform: Option<Transform>,
+    ) -> Vec<Self> {
+        if let Some(intersections) = object.intersections_with_children(ray, parent_transform) {
+            return intersections;
+        }
 
-        let intersection_times = object.times_of_intersections_with(&ray);
+        // Transforming the ray doesn't change the times of its intersections, so it's fine to find
+        // them with the ray in the parent's space.
+        let ray_in_parent_space =
+            parent_transform.map(|transform| transform.invert().unwrap() * ray);
+        let intersections_with_uv = match &ray_in_parent_space {
+            Some(parent_space_ray) => object.uv_intersections_with(parent_space_ray),
+            None => object.uv_intersections_with(ray),
+        };
+
+        let mut intersections = Vec::new();
 
-        for time in intersection_times {
-            let intersection = Intersection::new(time, object.to_owned(), ray);
+        for (time, uv) in intersections_with_uv {
+            let mut intersection = Intersection::new(time, object.to_owned(), ray);
+            intersection.uv = uv;
+            intersection.parent_transform = parent_transform;
             intersections.push(intersection)
         }
 
@@ -65,8 +97,30 @@ impl<'r> Intersection<'r> {
         }
     }
 
+    /// Converts a point from world space into the space of the intersected object, taking into
+    /// account any groups the object is in.
+    pub fn to_object_space(&self, world_space_point: Point) -> Point {
+        let full_transform = match self.parent_transform {
+            Some(parent_transform) => parent_transform * *self.object.transform(),
+            None => *self.object.transform(),
+        };
+
+        full_transform.invert().unwrap() * world_space_point
+    }
+
     fn base_normal_vector(&self) -> Vector {
-        self.object.normal_at(self.point())
+        match self.parent_transform {
+            Some(parent_transform) => {
+                // The object only knows how to find its normal in its parent's space, so we need to
+                // go there and back.
+                let parent_inverse = parent_transform.invert().unwrap();
+                let parent_space_point = parent_inverse * self.point();
+                let parent_space_normal = self.object.normal_at(parent_space_point, self.uv);
+
+                (parent_inverse.matrix().transpose() * parent_space_normal).normalize()
+            }
+            None => self.object.normal_at(self.point(), self.uv),
+        }
     }
 
     fn is_inside_object(&self) -> bool {
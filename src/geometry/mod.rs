@@ -1,23 +1,47 @@
+mod bounding_box;
+mod bvh;
 mod close_enough;
+mod cone;
+mod csg;
+mod cube;
+mod cylinder;
+mod group;
+mod hash;
 mod intersection;
 mod matrix;
 mod plane;
 mod point;
+mod polynomial;
 mod ray;
 mod shape;
+mod smooth_triangle;
 mod sphere;
+mod torus;
 mod transform;
+mod triangle;
 mod tuple;
 mod vector;
 
+pub use bounding_box::BoundingBox;
+pub use bvh::Bvh;
 pub use close_enough::*;
+pub use cone::Cone;
+pub use csg::{Csg, CsgOperation};
+pub use cube::Cube;
+pub use cylinder::Cylinder;
+pub use group::Group;
+pub use hash::*;
 pub use intersection::*;
 pub use matrix::IDENTITY_MATRIX;
 pub use plane::Plane;
 pub use point::*;
-pub use ray::Ray;
+pub use polynomial::*;
+pub use ray::{Ray, RayKind};
 pub use shape::*;
+pub use smooth_triangle::SmoothTriangle;
 pub use sphere::Sphere;
+pub use torus::Torus;
 pub use transform::*;
+pub use triangle::Triangle;
 pub use tuple::Tuple;
 pub use vector::*;
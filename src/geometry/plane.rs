@@ -4,6 +4,7 @@ use crate::prelude::*;
 pub struct Plane {
     transform: Transform,
     material: Material,
+    visibility: Visibility,
 }
 
 impl Plane {
@@ -11,6 +12,7 @@ impl Plane {
         Plane {
             transform: Transform::new(IDENTITY_MATRIX),
             material: MaterialBuilder::new().build(),
+            visibility: Visibility::default(),
         }
     }
 
@@ -18,12 +20,13 @@ impl Plane {
         Plane {
             transform: Transform::new(IDENTITY_MATRIX),
             material,
+            visibility: Visibility::default(),
         }
     }
 }
 
 impl Shape for Plane {
-    fn normal_at(&self, _world_space_point: Point) -> Vector {
+    fn normal_at(&self, _world_space_point: Point, _uv: Option<(f64, f64)>) -> Vector {
         self.transform * POSITIVE_Y
     }
 
@@ -49,6 +52,14 @@ impl Shape for Plane {
         self.material = material;
     }
 
+    fn visibility(&self) -> &Visibility {
+        &self.visibility
+    }
+
+    fn set_visibility(&mut self, visibility: Visibility) {
+        self.visibility = visibility;
+    }
+
     fn transform(&self) -> &Transform {
         &self.transform
     }
@@ -57,6 +68,13 @@ impl Shape for Plane {
         self.transform = transformation;
     }
 
+    fn bounds(&self) -> BoundingBox {
+        BoundingBox::new(
+            Point::new(f64::NEG_INFINITY, 0.0, f64::NEG_INFINITY),
+            Point::new(f64::INFINITY, 0.0, f64::INFINITY),
+        )
+    }
+
     fn shape_type(&self) -> ShapeType {
         ShapeType::Plane
     }
@@ -72,9 +90,12 @@ mod test {
     fn the_normal_vector_is_always_the_same() {
         let plane = Plane::new();
 
-        assert_eq!(plane.normal_at(ORIGIN), POSITIVE_Y);
-        assert_eq!(plane.normal_at(Point::new(1.0, 0.0, 0.0)), POSITIVE_Y);
-        assert_eq!(plane.normal_at(Point::new(8.0, 0.0, -3.0)), POSITIVE_Y);
+        assert_eq!(plane.normal_at(ORIGIN, None), POSITIVE_Y);
+        assert_eq!(plane.normal_at(Point::new(1.0, 0.0, 0.0), None), POSITIVE_Y);
+        assert_eq!(
+            plane.normal_at(Point::new(8.0, 0.0, -3.0), None),
+            POSITIVE_Y
+        );
     }
 
     #[test]
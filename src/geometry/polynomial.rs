@@ -0,0 +1,257 @@
+use std::f64::consts::PI;
+
+use crate::prelude::*;
+
+// How many steps of Newton's method are used to clean up the roots we find.
+const POLISHING_STEPS: usize = 3;
+
+/// Finds the real roots of `a·x² + b·x + c`, from smallest to largest. A root that's repeated will
+/// be in there twice.
+pub fn solve_quadratic(a: f64, b: f64, c: f64) -> Vec<f64> {
+    if a == 0.0 {
+        return if b == 0.0 { vec![] } else { vec![-c / b] };
+    }
+
+    let discriminant = b.powi(2) - 4.0 * a * c;
+
+    if discriminant < 0.0 {
+        return vec![];
+    }
+
+    // This way of finding the roots avoids subtracting two numbers that are nearly equal, which
+    // loses precision with the usual formula.
+    let q = -0.5 * (b + b.signum() * discriminant.sqrt());
+
+    let mut roots = if q == 0.0 {
+        vec![0.0, 0.0]
+    } else {
+        vec![q / a, c / q]
+    };
+
+    roots.sort_by(f64::total_cmp);
+    roots
+}
+
+/// Finds the real roots of `a·x³ + b·x² + c·x + d`, from smallest to largest.
+pub fn solve_cubic(a: f64, b: f64, c: f64, d: f64) -> Vec<f64> {
+    if a == 0.0 {
+        return solve_quadratic(b, c, d);
+    }
+
+    let (b, c, d) = (b / a, c / a, d / a);
+
+    // Substituting x = y - b/3 gets rid of the squared term, leaving y³ + p·y + q = 0.
+    let shift = b / 3.0;
+    let p = c - b.powi(2) / 3.0;
+    let q = 2.0 * b.powi(3) / 27.0 - b * c / 3.0 + d;
+
+    let discriminant = (q / 2.0).powi(2) + (p / 3.0).powi(3);
+
+    let mut roots = if close_enough(&discriminant, &0.0) {
+        if close_enough(&p, &0.0) {
+            vec![0.0]
+        } else {
+            vec![3.0 * q / p, -3.0 * q / (2.0 * p)]
+        }
+    } else if discriminant > 0.0 {
+        let u = (-q / 2.0 + discriminant.sqrt()).cbrt();
+        let v = (-q / 2.0 - discriminant.sqrt()).cbrt();
+        vec![u + v]
+    } else {
+        // Three real roots, which are easiest to find with a bit of trigonometry.
+        let radius = 2.0 * (-p / 3.0).sqrt();
+        let angle = ((3.0 * q) / (p * radius)).clamp(-1.0, 1.0).acos() / 3.0;
+
+        (0..3)
+            .map(|k| radius * (angle - 2.0 * PI * k as f64 / 3.0).cos())
+            .collect()
+    };
+
+    for root in &mut roots {
+        *root = polish(&[1.0, b, c, d], *root - shift);
+    }
+
+    roots.sort_by(f64::total_cmp);
+    roots
+}
+
+/// Finds the real roots of `a·x⁴ + b·x³ + c·x² + d·x + e`, from smallest to largest, using Ferrari's
+/// method. Each root is polished up with a few steps of Newton's method afterwards, since the
+/// closed form on its own can be quite far off when the coefficients are very different in size.
+pub fn solve_quartic(a: f64, b: f64, c: f64, d: f64, e: f64) -> Vec<f64> {
+    if a == 0.0 {
+        return solve_cubic(b, c, d, e);
+    }
+
+    let (b, c, d, e) = (b / a, c / a, d / a, e / a);
+
+    // Substituting x = y - b/4 gets rid of the cubed term, leaving y⁴ + p·y² + q·y + r = 0.
+    let shift = b / 4.0;
+    let p = c - 3.0 * b.powi(2) / 8.0;
+    let q = d - b * c / 2.0 + b.powi(3) / 8.0;
+    let r = e - b * d / 4.0 + b.powi(2) * c / 16.0 - 3.0 * b.powi(4) / 256.0;
+
+    let mut roots = Vec::new();
+
+    if close_enough(&q, &0.0) {
+        // With no linear term it's just a quadratic in y².
+        for y_squared in solve_quadratic(1.0, p, r) {
+            if y_squared >= 0.0 {
+                roots.push(y_squared.sqrt());
+                roots.push(-y_squared.sqrt());
+            }
+        }
+    } else {
+        // Any positive root m of this "resolvent" cubic lets the quartic be split into two
+        // quadratics, and there's always one when q isn't zero.
+        let m = solve_cubic(1.0, p, p.powi(2) / 4.0 - r, -q.powi(2) / 8.0)
+            .into_iter()
+            .fold(f64::NEG_INFINITY, f64::max);
+
+        if m <= 0.0 {
+            return vec![];
+        }
+
+        let root_of_2m = (2.0 * m).sqrt();
+        let offset = q / (2.0 * root_of_2m);
+
+        roots.append(&mut solve_quadratic(1.0, root_of_2m, p / 2.0 + m - offset));
+        roots.append(&mut solve_quadratic(1.0, -root_of_2m, p / 2.0 + m + offset));
+    }
+
+    for root in &mut roots {
+        *root = polish(&[1.0, b, c, d, e], *root - shift);
+    }
+
+    roots.sort_by(f64::total_cmp);
+    roots
+}
+
+// Evaluates a polynomial with the given coefficients (highest power first) along with its
+// derivative.
+fn evaluate(coefficients: &[f64], x: f64) -> (f64, f64) {
+    let mut value = 0.0;
+    let mut derivative = 0.0;
+
+    for coefficient in coefficients {
+        derivative = derivative * x + value;
+        value = value * x + coefficient;
+    }
+
+    (value, derivative)
+}
+
+// Newton's method only gets used as long as it keeps making things better, since near a repeated
+// root the derivative goes to zero and the steps can go a long way off.
+fn polish(coefficients: &[f64], root: f64) -> f64 {
+    let mut root = root;
+    let (mut value, _) = evaluate(coefficients, root);
+
+    for _ in 0..POLISHING_STEPS {
+        let (_, derivative) = evaluate(coefficients, root);
+        if derivative == 0.0 {
+            break;
+        }
+
+        let candidate = root - value / derivative;
+        let (candidate_value, _) = evaluate(coefficients, candidate);
+
+        if candidate_value.abs() >= value.abs() {
+            break;
+        }
+
+        root = candidate;
+        value = candidate_value;
+    }
+
+    root
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_roots(roots: Vec<f64>, expected: &[f64]) {
+        assert_eq!(roots.len(), expected.len(), "got {:?}", roots);
+        for (root, expected_root) in roots.iter().zip(expected) {
+            assert!(
+                close_enough(root, expected_root),
+                "got {:?}, expected {:?}",
+                roots,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn solving_quadratics() {
+        assert_roots(solve_quadratic(1.0, -3.0, 2.0), &[1.0, 2.0]);
+        assert_roots(solve_quadratic(2.0, 0.0, -8.0), &[-2.0, 2.0]);
+        assert_roots(solve_quadratic(1.0, 0.0, 1.0), &[]);
+        assert_roots(solve_quadratic(0.0, 2.0, -4.0), &[2.0]);
+    }
+
+    #[test]
+    fn solving_cubics() {
+        // (x + 1)(x - 2)(x - 3)
+        assert_roots(solve_cubic(1.0, -4.0, 1.0, 6.0), &[-1.0, 2.0, 3.0]);
+        // (x - 1)(x² + 1)
+        assert_roots(solve_cubic(1.0, -1.0, 1.0, -1.0), &[1.0]);
+        // 2(x - 1)³
+        assert_roots(solve_cubic(2.0, -6.0, 6.0, -2.0), &[1.0]);
+    }
+
+    #[test]
+    fn solving_a_quartic_with_four_real_roots() {
+        // (x - 1)(x - 2)(x - 3)(x - 4)
+        assert_roots(
+            solve_quartic(1.0, -10.0, 35.0, -50.0, 24.0),
+            &[1.0, 2.0, 3.0, 4.0],
+        );
+    }
+
+    #[test]
+    fn solving_a_quartic_with_two_real_roots() {
+        // (x + 2)(x - 5)(x² + 1)
+        assert_roots(solve_quartic(1.0, -3.0, -9.0, -3.0, -10.0), &[-2.0, 5.0]);
+    }
+
+    #[test]
+    fn solving_a_quartic_with_no_real_roots() {
+        assert_roots(solve_quartic(1.0, 0.0, 0.0, 0.0, 1.0), &[]);
+    }
+
+    #[test]
+    fn solving_a_quartic_with_repeated_roots() {
+        // (x - 1)²(x + 1)²
+        assert_roots(
+            solve_quartic(1.0, 0.0, -2.0, 0.0, 1.0),
+            &[-1.0, -1.0, 1.0, 1.0],
+        );
+    }
+
+    #[test]
+    fn solving_a_quartic_with_roots_of_very_different_sizes() {
+        // (x - 0.001)(x - 1)(x - 1000)(x - 1001), written out.
+        let roots = [0.001, 1.0, 1000.0, 1001.0];
+        let b = -(roots[0] + roots[1] + roots[2] + roots[3]);
+        let c = roots[0] * roots[1]
+            + roots[0] * roots[2]
+            + roots[0] * roots[3]
+            + roots[1] * roots[2]
+            + roots[1] * roots[3]
+            + roots[2] * roots[3];
+        let d = -(roots[0] * roots[1] * roots[2]
+            + roots[0] * roots[1] * roots[3]
+            + roots[0] * roots[2] * roots[3]
+            + roots[1] * roots[2] * roots[3]);
+        let e = roots[0] * roots[1] * roots[2] * roots[3];
+
+        assert_roots(solve_quartic(1.0, b, c, d, e), &roots);
+    }
+
+    #[test]
+    fn a_quartic_without_a_fourth_power_is_solved_as_a_cubic() {
+        assert_roots(solve_quartic(0.0, 1.0, -4.0, 1.0, 6.0), &[-1.0, 2.0, 3.0]);
+    }
+}
@@ -1,14 +1,34 @@
 use crate::prelude::*;
 
+/// What a ray is being cast for, which decides which shapes it can see.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RayKind {
+    /// Rays from the camera.
+    Camera,
+    /// Rays that have been reflected or refracted off of something.
+    Secondary,
+    /// Rays from a point towards a light, looking for anything that casts a shadow.
+    Shadow,
+}
+
 #[derive(PartialEq, Debug)]
 pub struct Ray {
     origin: Point,
     direction: Vector,
+    kind: RayKind,
 }
 
 impl Ray {
     pub fn new(origin: Point, direction: Vector) -> Self {
-        Ray { origin, direction }
+        Ray::new_of_kind(origin, direction, RayKind::Camera)
+    }
+
+    pub fn new_of_kind(origin: Point, direction: Vector, kind: RayKind) -> Self {
+        Ray {
+            origin,
+            direction,
+            kind,
+        }
     }
 
     pub fn origin(&self) -> &Point {
@@ -19,6 +39,10 @@ impl Ray {
         &self.direction
     }
 
+    pub fn kind(&self) -> RayKind {
+        self.kind
+    }
+
     pub fn position_at(&self, time: f64) -> Point {
         self.origin + self.direction * time
     }
@@ -2,15 +2,47 @@ use crate::prelude::*;
 
 // TODO: Would be nice to have a `ShapeBuilder` that could make any shape...
 pub trait Shape {
-    fn normal_at(&self, world_space_point: Point) -> Vector;
+    /// The `uv` values are the ones carried by the `Intersection` the normal is being found for, if
+    /// there are any. Most shapes don't need them.
+    fn normal_at(&self, world_space_point: Point, uv: Option<(f64, f64)>) -> Vector;
     fn times_of_intersections_with<'s, 'r>(&'s self, ray: &'r Ray) -> Vec<f64>
     where
         'r: 's;
+    /// Like `times_of_intersections_with`, but each time also comes with the u/v coordinates of
+    /// where the intersection happened on the shape's surface, for those shapes that have them.
+    fn uv_intersections_with<'s, 'r>(&'s self, ray: &'r Ray) -> Vec<(f64, Option<(f64, f64)>)>
+    where
+        'r: 's,
+    {
+        self.times_of_intersections_with(ray)
+            .into_iter()
+            .map(|time| (time, None))
+            .collect()
+    }
+    /// Shapes that are made up of other shapes (like groups) find their intersections here instead,
+    /// so that they can hand back intersections with the shapes inside of them. The
+    /// `parent_transform` is the combined transform of any groups this shape is itself inside of.
+    fn intersections_with_children<'r>(
+        &self,
+        _ray: &'r Ray,
+        _parent_transform: Option<Transform>,
+    ) -> Option<Vec<Intersection<'r>>> {
+        None
+    }
     fn transform(&self) -> &Transform;
     fn set_transform(&mut self, transformation: Transform);
     fn material(&self) -> &Material;
     fn set_material(&mut self, material: Material);
+    fn visibility(&self) -> &Visibility;
+    fn set_visibility(&mut self, visibility: Visibility);
     fn shape_type(&self) -> ShapeType;
+    /// The box that the shape fits within, in object space.
+    fn bounds(&self) -> BoundingBox;
+    /// The box that the shape fits within once it's been transformed, which is in the space of
+    /// whatever the shape is inside of (a group, or just the world).
+    fn parent_space_bounds(&self) -> BoundingBox {
+        self.bounds().transformed(self.transform())
+    }
 }
 
 impl PartialEq for dyn Shape {
@@ -19,8 +51,62 @@ impl PartialEq for dyn Shape {
     }
 }
 
+/// Which kinds of rays can see a shape, for things like a backdrop that shouldn't cast a shadow, or
+/// something that only casts a shadow without being seen itself.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Visibility {
+    casts_shadow: bool,
+    visible_to_camera: bool,
+    visible_in_reflections: bool,
+}
+
+impl Visibility {
+    pub fn new(casts_shadow: bool, visible_to_camera: bool, visible_in_reflections: bool) -> Self {
+        Visibility {
+            casts_shadow,
+            visible_to_camera,
+            visible_in_reflections,
+        }
+    }
+
+    pub fn casts_shadow(&self) -> bool {
+        self.casts_shadow
+    }
+
+    pub fn visible_to_camera(&self) -> bool {
+        self.visible_to_camera
+    }
+
+    /// Refracted rays count as reflections too, since both show the shape somewhere else.
+    pub fn visible_in_reflections(&self) -> bool {
+        self.visible_in_reflections
+    }
+
+    pub fn is_seen_by(&self, kind: RayKind) -> bool {
+        match kind {
+            RayKind::Camera => self.visible_to_camera,
+            RayKind::Secondary => self.visible_in_reflections,
+            RayKind::Shadow => self.casts_shadow,
+        }
+    }
+}
+
+impl Default for Visibility {
+    fn default() -> Self {
+        Visibility::new(true, true, true)
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub enum ShapeType {
     Sphere,
     Plane,
+    Cube,
+    Cylinder,
+    Cone,
+    Triangle,
+    SmoothTriangle,
+    Group,
+    Csg,
+    Torus,
 }
@@ -0,0 +1,160 @@
+use crate::prelude::*;
+
+use super::triangle::intersect_triangle;
+
+/// A triangle with a normal given for each of its points, which are interpolated across its surface
+/// so that a mesh of them can look curved.
+#[derive(Debug, PartialEq)]
+pub struct SmoothTriangle {
+    p1: Point,
+    p2: Point,
+    p3: Point,
+    n1: Vector,
+    n2: Vector,
+    n3: Vector,
+    e1: Vector,
+    e2: Vector,
+    transform: Transform,
+    material: Material,
+    visibility: Visibility,
+}
+
+impl SmoothTriangle {
+    pub fn new(p1: Point, p2: Point, p3: Point, n1: Vector, n2: Vector, n3: Vector) -> Self {
+        SmoothTriangle {
+            p1,
+            p2,
+            p3,
+            n1,
+            n2,
+            n3,
+            e1: p2 - p1,
+            e2: p3 - p1,
+            transform: Transform::new(IDENTITY_MATRIX),
+            material: MaterialBuilder::new().build(),
+            visibility: Visibility::default(),
+        }
+    }
+}
+
+impl Shape for SmoothTriangle {
+    fn normal_at(&self, _world_space_point: Point, uv: Option<(f64, f64)>) -> Vector {
+        // Without any u/v values we'll just end up with the normal at the first point.
+        let (u, v) = uv.unwrap_or((0.0, 0.0));
+
+        let object_space_normal = self.n2 * u + self.n3 * v + self.n1 * (1.0 - u - v);
+
+        let transform_inverse = self.transform.invert().unwrap();
+        let world_space_normal = transform_inverse.matrix().transpose() * object_space_normal;
+
+        world_space_normal.normalize()
+    }
+
+    fn times_of_intersections_with<'s, 'r>(&'s self, ray: &'r Ray) -> Vec<f64>
+    where
+        'r: 's,
+    {
+        self.uv_intersections_with(ray)
+            .into_iter()
+            .map(|(time, _uv)| time)
+            .collect()
+    }
+
+    fn uv_intersections_with<'s, 'r>(&'s self, ray: &'r Ray) -> Vec<(f64, Option<(f64, f64)>)>
+    where
+        'r: 's,
+    {
+        let local_ray = self.transform.invert().unwrap() * ray;
+
+        match intersect_triangle(&self.p1, &self.e1, &self.e2, &local_ray) {
+            Some((time, u, v)) => vec![(time, Some((u, v)))],
+            None => vec![],
+        }
+    }
+
+    fn transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    fn set_transform(&mut self, transformation: Transform) {
+        self.transform = transformation;
+    }
+
+    fn material(&self) -> &Material {
+        &self.material
+    }
+
+    fn set_material(&mut self, material: Material) {
+        self.material = material;
+    }
+
+    fn visibility(&self) -> &Visibility {
+        &self.visibility
+    }
+
+    fn set_visibility(&mut self, visibility: Visibility) {
+        self.visibility = visibility;
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        let mut bounds = BoundingBox::empty();
+        for point in [self.p1, self.p2, self.p3] {
+            bounds.add_point(point);
+        }
+
+        bounds
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::SmoothTriangle
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::rc::Rc;
+
+    fn create_test_triangle() -> SmoothTriangle {
+        SmoothTriangle::new(
+            Point::new(0.0, 1.0, 0.0),
+            Point::new(-1.0, 0.0, 0.0),
+            Point::new(1.0, 0.0, 0.0),
+            POSITIVE_Y,
+            NEGATIVE_X,
+            POSITIVE_X,
+        )
+    }
+
+    #[test]
+    fn an_intersection_with_a_smooth_triangle_stores_u_and_v() {
+        let triangle = create_test_triangle();
+        let ray = Ray::new(Point::new(-0.2, 0.3, -2.0), POSITIVE_Z);
+
+        let intersections = triangle.uv_intersections_with(&ray);
+        let (u, v) = intersections[0].1.unwrap();
+
+        assert!(close_enough(&u, &0.45));
+        assert!(close_enough(&v, &0.25));
+    }
+
+    #[test]
+    fn a_smooth_triangle_uses_u_and_v_to_interpolate_the_normal() {
+        let triangle = create_test_triangle();
+
+        let normal = triangle.normal_at(ORIGIN, Some((0.45, 0.25)));
+
+        assert_eq!(normal, Vector::new(-0.5547, 0.83205, 0.0));
+    }
+
+    #[test]
+    fn the_normal_vector_of_a_hit_on_a_smooth_triangle_is_interpolated() {
+        let triangle: Rc<dyn Shape> = Rc::new(create_test_triangle());
+        let ray = Ray::new(Point::new(-0.2, 0.3, -2.0), POSITIVE_Z);
+
+        let intersections = Intersection::of(&triangle, &ray);
+        let hit = intersections.first().unwrap();
+
+        assert_eq!(hit.normal_vector(), Vector::new(-0.5547, 0.83205, 0.0));
+    }
+}
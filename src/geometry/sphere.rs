@@ -6,6 +6,7 @@ pub struct Sphere {
     radius: f64,
     transform: Transform,
     material: Material,
+    visibility: Visibility,
 }
 
 impl Sphere {
@@ -15,6 +16,7 @@ impl Sphere {
             radius: 1.0,
             transform: Transform::new(IDENTITY_MATRIX),
             material: MaterialBuilder::new().build(),
+            visibility: Visibility::default(),
         }
     }
 
@@ -24,6 +26,7 @@ impl Sphere {
             radius: 1.0,
             transform: Transform::new(IDENTITY_MATRIX),
             material,
+            visibility: Visibility::default(),
         }
     }
 
@@ -33,7 +36,7 @@ impl Sphere {
 }
 
 impl Shape for Sphere {
-    fn normal_at(&self, world_space_point: Point) -> Vector {
+    fn normal_at(&self, world_space_point: Point, _uv: Option<(f64, f64)>) -> Vector {
         let transform_inverse = self.transform.invert().unwrap();
         let object_space_point = transform_inverse * world_space_point;
 
@@ -86,6 +89,18 @@ impl Shape for Sphere {
         self.material = material;
     }
 
+    fn visibility(&self) -> &Visibility {
+        &self.visibility
+    }
+
+    fn set_visibility(&mut self, visibility: Visibility) {
+        self.visibility = visibility;
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        BoundingBox::new(Point::new(-1.0, -1.0, -1.0), Point::new(1.0, 1.0, 1.0))
+    }
+
     fn shape_type(&self) -> ShapeType {
         ShapeType::Sphere
     }
@@ -148,7 +163,7 @@ mod test {
     #[test]
     fn getting_normal_on_x_axis() {
         let sphere = Sphere::new();
-        let normal = sphere.normal_at(Point::new(1.0, 0.0, 0.0));
+        let normal = sphere.normal_at(Point::new(1.0, 0.0, 0.0), None);
 
         assert_eq!(normal, Vector::new(1.0, 0.0, 0.0));
     }
@@ -156,7 +171,7 @@ mod test {
     #[test]
     fn getting_normal_on_y_axis() {
         let sphere = Sphere::new();
-        let normal = sphere.normal_at(Point::new(0.0, 1.0, 0.0));
+        let normal = sphere.normal_at(Point::new(0.0, 1.0, 0.0), None);
 
         assert_eq!(normal, Vector::new(0.0, 1.0, 0.0));
     }
@@ -164,7 +179,7 @@ mod test {
     #[test]
     fn getting_normal_on_z_axis() {
         let sphere = Sphere::new();
-        let normal = sphere.normal_at(Point::new(0.0, 0.0, 1.0));
+        let normal = sphere.normal_at(Point::new(0.0, 0.0, 1.0), None);
 
         assert_eq!(normal, Vector::new(0.0, 0.0, 1.0));
     }
@@ -172,11 +187,14 @@ mod test {
     #[test]
     fn getting_normal_at_a_nonaxial_point() {
         let sphere = Sphere::new();
-        let normal = sphere.normal_at(Point::new(
-            3.0f64.sqrt() / 3.0,
-            3.0f64.sqrt() / 3.0,
-            3.0f64.sqrt() / 3.0,
-        ));
+        let normal = sphere.normal_at(
+            Point::new(
+                3.0f64.sqrt() / 3.0,
+                3.0f64.sqrt() / 3.0,
+                3.0f64.sqrt() / 3.0,
+            ),
+            None,
+        );
 
         assert_eq!(
             normal,
@@ -191,7 +209,7 @@ mod test {
     #[test]
     fn the_normal_is_a_normalized_vector() {
         let sphere = Sphere::new();
-        let normal = sphere.normal_at(Point::new(0.0, 0.0, 1.0));
+        let normal = sphere.normal_at(Point::new(0.0, 0.0, 1.0), None);
 
         assert_eq!(normal, normal.normalize());
     }
@@ -201,7 +219,7 @@ mod test {
         let mut sphere = Sphere::new();
         sphere.set_transform(Transform::translation(0.0, 1.0, 0.0));
 
-        let normal = sphere.normal_at(Point::new(0.0, 1.70711, -0.70711));
+        let normal = sphere.normal_at(Point::new(0.0, 1.70711, -0.70711), None);
 
         assert_eq!(normal, Vector::new(0.0, 0.70711, -0.70711));
     }
@@ -211,7 +229,7 @@ mod test {
         let mut sphere = Sphere::new();
         sphere.set_transform(Transform::scaling(1.0, 0.5, 1.0) * Transform::z_rotation(PI / 5.0));
 
-        let normal = sphere.normal_at(Point::new(0.0, 2f64.sqrt() / 2.0, -2f64.sqrt() / 2.0));
+        let normal = sphere.normal_at(Point::new(0.0, 2f64.sqrt() / 2.0, -2f64.sqrt() / 2.0), None);
 
         assert_eq!(normal, Vector::new(0.0, 0.97014, -0.24254));
     }
@@ -0,0 +1,224 @@
+use crate::prelude::*;
+
+/// A donut lying flat in the xz plane, centered on the origin. The `major_radius` goes from the
+/// origin to the middle of the tube, and the `minor_radius` is the radius of the tube itself.
+#[derive(Debug, PartialEq)]
+pub struct Torus {
+    major_radius: f64,
+    minor_radius: f64,
+    transform: Transform,
+    material: Material,
+    visibility: Visibility,
+}
+
+impl Torus {
+    pub fn new(major_radius: f64, minor_radius: f64) -> Self {
+        Torus {
+            major_radius,
+            minor_radius,
+            transform: Transform::new(IDENTITY_MATRIX),
+            material: MaterialBuilder::new().build(),
+            visibility: Visibility::default(),
+        }
+    }
+}
+
+impl Shape for Torus {
+    fn normal_at(&self, world_space_point: Point, _uv: Option<(f64, f64)>) -> Vector {
+        let transform_inverse = self.transform.invert().unwrap();
+        let object_space_point = transform_inverse * world_space_point;
+
+        let x = *object_space_point.x();
+        let y = *object_space_point.y();
+        let z = *object_space_point.z();
+
+        // This is the gradient of the torus' implicit equation at the point.
+        let major_radius_squared = self.major_radius.powi(2);
+        let parameter =
+            x.powi(2) + y.powi(2) + z.powi(2) - major_radius_squared - self.minor_radius.powi(2);
+
+        let object_space_normal = Vector::new(
+            x * parameter,
+            y * (parameter + 2.0 * major_radius_squared),
+            z * parameter,
+        );
+
+        let world_space_normal = transform_inverse.matrix().transpose() * object_space_normal;
+
+        world_space_normal.normalize()
+    }
+
+    fn times_of_intersections_with<'s, 'r>(&'s self, ray: &'r Ray) -> Vec<f64>
+    where
+        'r: 's,
+    {
+        let local_ray = self.transform.invert().unwrap() * ray;
+        let direction = local_ray.direction();
+
+        // The quartic gets badly behaved when the ray starts a long way from the torus, so we solve
+        // it from the point on the ray closest to the center instead, and shift the times back
+        // afterwards. That also lets us skip rays that don't come near the torus at all.
+        let direction_squared = dot(direction, direction);
+        let shift = -dot(&(*local_ray.origin() - ORIGIN), direction) / direction_squared;
+        let origin = local_ray.position_at(shift) - ORIGIN;
+
+        let outer_radius = self.major_radius + self.minor_radius;
+        if dot(&origin, &origin) > outer_radius.powi(2) {
+            return vec![];
+        }
+
+        let major_radius_squared = self.major_radius.powi(2);
+        let origin_dot_direction = dot(&origin, direction);
+        let e = dot(&origin, &origin) - major_radius_squared - self.minor_radius.powi(2);
+
+        let a = direction_squared.powi(2);
+        let b = 4.0 * direction_squared * origin_dot_direction;
+        let c = 2.0 * direction_squared * e
+            + 4.0 * origin_dot_direction.powi(2)
+            + 4.0 * major_radius_squared * direction.y().powi(2);
+        let d = 4.0 * origin_dot_direction * e
+            + 8.0 * major_radius_squared * origin.y() * direction.y();
+        let e = e.powi(2)
+            - 4.0 * major_radius_squared * (self.minor_radius.powi(2) - origin.y().powi(2));
+
+        solve_quartic(a, b, c, d, e)
+            .into_iter()
+            .map(|t| t + shift)
+            .collect()
+    }
+
+    fn transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    fn set_transform(&mut self, transformation: Transform) {
+        self.transform = transformation;
+    }
+
+    fn material(&self) -> &Material {
+        &self.material
+    }
+
+    fn set_material(&mut self, material: Material) {
+        self.material = material;
+    }
+
+    fn visibility(&self) -> &Visibility {
+        &self.visibility
+    }
+
+    fn set_visibility(&mut self, visibility: Visibility) {
+        self.visibility = visibility;
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        let outer_radius = self.major_radius + self.minor_radius;
+
+        BoundingBox::new(
+            Point::new(-outer_radius, -self.minor_radius, -outer_radius),
+            Point::new(outer_radius, self.minor_radius, outer_radius),
+        )
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::Torus
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn a_ray_through_the_middle_of_a_torus_hits_both_sides_of_the_tube() {
+        let torus = Torus::new(1.0, 0.25);
+        let ray = Ray::new(Point::new(-5.0, 0.0, 0.0), POSITIVE_X);
+
+        let times = torus.times_of_intersections_with(&ray);
+
+        assert_eq!(times.len(), 4);
+        for (time, expected) in times.iter().zip([3.75, 4.25, 5.75, 6.25]) {
+            assert!(close_enough(time, &expected), "got {:?}", times);
+        }
+    }
+
+    #[test]
+    fn a_ray_from_above_hits_the_tube_or_goes_through_the_hole() {
+        let torus = Torus::new(1.0, 0.25);
+
+        let through_the_tube = Ray::new(Point::new(1.0, 5.0, 0.0), NEGATIVE_Y);
+        let times = torus.times_of_intersections_with(&through_the_tube);
+        assert_eq!(times.len(), 2);
+        assert!(close_enough(&times[0], &4.75));
+        assert!(close_enough(&times[1], &5.25));
+
+        let through_the_hole = Ray::new(Point::new(0.0, 5.0, 0.0), NEGATIVE_Y);
+        assert!(torus
+            .times_of_intersections_with(&through_the_hole)
+            .is_empty());
+    }
+
+    #[test]
+    fn a_ray_misses_a_torus() {
+        let torus = Torus::new(1.0, 0.25);
+        let ray = Ray::new(Point::new(-5.0, 0.5, 0.0), POSITIVE_X);
+
+        assert!(torus.times_of_intersections_with(&ray).is_empty());
+    }
+
+    #[test]
+    fn intersections_with_a_torus_a_long_way_off_are_still_accurate() {
+        let torus = Torus::new(1.0, 0.25);
+        let ray = Ray::new(
+            Point::new(-10000.0, 0.1, 0.0),
+            Vector::new(1.0, 0.0, 0.0001).normalize(),
+        );
+
+        for t in torus.times_of_intersections_with(&ray) {
+            let point = ray.position_at(t);
+            let distance_from_tube_center =
+                ((point.x().powi(2) + point.z().powi(2)).sqrt() - 1.0).hypot(*point.y());
+
+            assert!(close_enough(&distance_from_tube_center, &0.25));
+        }
+    }
+
+    #[test]
+    fn intersecting_a_transformed_torus() {
+        let mut torus = Torus::new(2.0, 0.5);
+        torus.set_transform(Transform::translation(0.0, 0.0, 10.0) * Transform::x_rotation(1.5));
+
+        let ray = Ray::new(ORIGIN, POSITIVE_Z);
+
+        // Standing the torus up means the ray goes straight through the hole.
+        assert!(torus.times_of_intersections_with(&ray).is_empty());
+
+        let ray = Ray::new(Point::new(2.0, 0.0, 0.0), POSITIVE_Z);
+        assert_eq!(torus.times_of_intersections_with(&ray).len(), 2);
+    }
+
+    #[test]
+    fn the_normal_on_a_torus_points_away_from_the_middle_of_the_tube() {
+        let torus = Torus::new(1.0, 0.25);
+
+        let cases = [
+            (Point::new(1.25, 0.0, 0.0), POSITIVE_X),
+            (Point::new(0.75, 0.0, 0.0), NEGATIVE_X),
+            (Point::new(1.0, 0.25, 0.0), POSITIVE_Y),
+            (Point::new(0.0, -0.25, 1.0), NEGATIVE_Y),
+            (Point::new(0.0, 0.0, -1.25), NEGATIVE_Z),
+        ];
+
+        for (point, expected_normal) in cases {
+            assert_eq!(torus.normal_at(point, None), expected_normal);
+        }
+    }
+
+    #[test]
+    fn the_bounds_of_a_torus() {
+        let bounds = Torus::new(2.0, 0.5).bounds();
+
+        assert_eq!(*bounds.minimum(), Point::new(-2.5, -0.5, -2.5));
+        assert_eq!(*bounds.maximum(), Point::new(2.5, 0.5, 2.5));
+    }
+}
@@ -136,7 +136,7 @@ impl ops::Mul<&Ray> for Transform {
     fn mul(self, rhs: &Ray) -> Self::Output {
         let new_origin = self * rhs.origin().to_owned();
         let new_direction = self * rhs.direction().to_owned();
-        Ray::new(new_origin, new_direction)
+        Ray::new_of_kind(new_origin, new_direction, rhs.kind())
     }
 }
 
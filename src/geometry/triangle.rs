@@ -0,0 +1,243 @@
+use crate::prelude::*;
+
+#[derive(Debug, PartialEq)]
+pub struct Triangle {
+    p1: Point,
+    p2: Point,
+    p3: Point,
+    e1: Vector,
+    e2: Vector,
+    normal: Vector,
+    transform: Transform,
+    material: Material,
+    visibility: Visibility,
+}
+
+impl Triangle {
+    pub fn new(p1: Point, p2: Point, p3: Point) -> Self {
+        let e1 = p2 - p1;
+        let e2 = p3 - p1;
+
+        Triangle {
+            p1,
+            p2,
+            p3,
+            e1,
+            e2,
+            normal: cross(&e2, &e1).normalize(),
+            transform: Transform::new(IDENTITY_MATRIX),
+            material: MaterialBuilder::new().build(),
+            visibility: Visibility::default(),
+        }
+    }
+}
+
+impl Shape for Triangle {
+    fn normal_at(&self, _world_space_point: Point, _uv: Option<(f64, f64)>) -> Vector {
+        let transform_inverse = self.transform.invert().unwrap();
+        let world_space_normal = transform_inverse.matrix().transpose() * self.normal;
+
+        world_space_normal.normalize()
+    }
+
+    fn times_of_intersections_with<'s, 'r>(&'s self, ray: &'r Ray) -> Vec<f64>
+    where
+        'r: 's,
+    {
+        self.uv_intersections_with(ray)
+            .into_iter()
+            .map(|(time, _uv)| time)
+            .collect()
+    }
+
+    fn uv_intersections_with<'s, 'r>(&'s self, ray: &'r Ray) -> Vec<(f64, Option<(f64, f64)>)>
+    where
+        'r: 's,
+    {
+        let local_ray = self.transform.invert().unwrap() * ray;
+
+        match intersect_triangle(&self.p1, &self.e1, &self.e2, &local_ray) {
+            Some((time, u, v)) => vec![(time, Some((u, v)))],
+            None => vec![],
+        }
+    }
+
+    fn transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    fn set_transform(&mut self, transformation: Transform) {
+        self.transform = transformation;
+    }
+
+    fn material(&self) -> &Material {
+        &self.material
+    }
+
+    fn set_material(&mut self, material: Material) {
+        self.material = material;
+    }
+
+    fn visibility(&self) -> &Visibility {
+        &self.visibility
+    }
+
+    fn set_visibility(&mut self, visibility: Visibility) {
+        self.visibility = visibility;
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        let mut bounds = BoundingBox::empty();
+        for point in [self.p1, self.p2, self.p3] {
+            bounds.add_point(point);
+        }
+
+        bounds
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::Triangle
+    }
+}
+
+/// Intersects an object space ray with the triangle starting at `p1` with the edges `e1` and `e2`,
+/// using the Möller–Trumbore algorithm.
+///
+/// If there is an intersection, the returned values are its time, followed by the `u` and `v`
+/// barycentric coordinates of where it happened (relative to the second and third points).
+pub fn intersect_triangle(
+    p1: &Point,
+    e1: &Vector,
+    e2: &Vector,
+    ray: &Ray,
+) -> Option<(f64, f64, f64)> {
+    let direction_cross_e2 = cross(ray.direction(), e2);
+    let determinant = dot(e1, &direction_cross_e2);
+
+    // If the determinant is zero, the ray is parallel to the triangle.
+    if determinant.abs() < EPSILON {
+        return None;
+    }
+
+    let f = 1.0 / determinant;
+
+    let p1_to_origin = *ray.origin() - *p1;
+    let u = f * dot(&p1_to_origin, &direction_cross_e2);
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+
+    let origin_cross_e1 = cross(&p1_to_origin, e1);
+    let v = f * dot(ray.direction(), &origin_cross_e1);
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+
+    let time = f * dot(e2, &origin_cross_e1);
+
+    Some((time, u, v))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn create_test_triangle() -> Triangle {
+        Triangle::new(
+            Point::new(0.0, 1.0, 0.0),
+            Point::new(-1.0, 0.0, 0.0),
+            Point::new(1.0, 0.0, 0.0),
+        )
+    }
+
+    #[test]
+    fn constructing_a_triangle_precomputes_the_edges_and_normal() {
+        let triangle = create_test_triangle();
+
+        assert_eq!(triangle.e1, Vector::new(-1.0, -1.0, 0.0));
+        assert_eq!(triangle.e2, Vector::new(1.0, -1.0, 0.0));
+        assert_eq!(triangle.normal, NEGATIVE_Z);
+    }
+
+    #[test]
+    fn the_normal_of_a_triangle_is_the_same_everywhere() {
+        let triangle = create_test_triangle();
+
+        assert_eq!(
+            triangle.normal_at(Point::new(0.0, 0.5, 0.0), None),
+            triangle.normal
+        );
+        assert_eq!(
+            triangle.normal_at(Point::new(-0.5, 0.75, 0.0), None),
+            triangle.normal
+        );
+        assert_eq!(
+            triangle.normal_at(Point::new(0.5, 0.25, 0.0), None),
+            triangle.normal
+        );
+    }
+
+    #[test]
+    fn a_ray_parallel_to_a_triangle_misses() {
+        let triangle = create_test_triangle();
+        let ray = Ray::new(Point::new(0.0, -1.0, -2.0), POSITIVE_Y);
+
+        assert!(triangle.times_of_intersections_with(&ray).is_empty());
+    }
+
+    #[test]
+    fn a_ray_misses_each_edge_of_a_triangle() {
+        let triangle = create_test_triangle();
+
+        let origins = [
+            Point::new(1.0, 1.0, -2.0),
+            Point::new(-1.0, 1.0, -2.0),
+            Point::new(0.0, -1.0, -2.0),
+        ];
+
+        for origin in origins {
+            let ray = Ray::new(origin, POSITIVE_Z);
+            assert!(triangle.times_of_intersections_with(&ray).is_empty());
+        }
+    }
+
+    #[test]
+    fn a_ray_strikes_a_triangle() {
+        let triangle = create_test_triangle();
+        let ray = Ray::new(Point::new(0.0, 0.5, -2.0), POSITIVE_Z);
+
+        let times = triangle.times_of_intersections_with(&ray);
+
+        assert_eq!(times, vec![2.0]);
+    }
+
+    #[test]
+    fn intersecting_a_triangle_gives_the_u_and_v_of_the_hit() {
+        let triangle = create_test_triangle();
+        let ray = Ray::new(Point::new(-0.2, 0.3, -2.0), POSITIVE_Z);
+
+        let intersections = triangle.uv_intersections_with(&ray);
+
+        assert_eq!(intersections.len(), 1);
+
+        let (time, uv) = intersections[0];
+        let (u, v) = uv.unwrap();
+        assert!(close_enough(&time, &2.0));
+        assert!(close_enough(&u, &0.45));
+        assert!(close_enough(&v, &0.25));
+    }
+
+    #[test]
+    fn the_bounds_of_a_triangle_fit_its_points() {
+        let triangle = Triangle::new(
+            Point::new(-3.0, 7.0, 2.0),
+            Point::new(6.0, 2.0, -4.0),
+            Point::new(2.0, -1.0, -1.0),
+        );
+
+        let bounds = triangle.bounds();
+
+        assert_eq!(*bounds.minimum(), Point::new(-3.0, -1.0, -4.0));
+        assert_eq!(*bounds.maximum(), Point::new(6.0, 7.0, 2.0));
+    }
+}
@@ -1,7 +1,10 @@
 mod parse_camera;
 mod parse_light;
 mod parse_little_things;
+mod parse_obj;
+mod parse_pattern;
 mod parse_shape;
 mod parse_yaml;
 
+pub use parse_obj::{parse_obj, parse_obj_file, ObjParsingError, ParsedObj};
 pub use parse_yaml::parse_scene_from_yaml;
@@ -8,14 +8,181 @@ use yaml_rust::Yaml;
 pub fn parse_light(map: &LinkedHashMap<Yaml, Yaml>) -> Result<PointLight, Box<dyn Error>> {
     let mut at: Option<Point> = None;
     let mut intensity: Option<Color> = None;
+    let mut attenuation = Attenuation::None;
 
     for (key, value) in map {
         match key.as_str().unwrap() {
             "at" => at = Some(parse_point(value.as_vec().unwrap().to_owned()).unwrap()),
             "intensity" => intensity = Some(parse_color(value).unwrap()),
+            "attenuation" => attenuation = parse_attenuation(value)?,
             _ => todo!(),
         }
     }
 
-    Ok(PointLight::new(intensity.unwrap(), at.unwrap()))
+    let mut light = PointLight::new(intensity.unwrap(), at.unwrap());
+    light.set_attenuation(attenuation);
+
+    Ok(light)
+}
+
+// Attenuation is either `none`, `inverse_square`, or a map with any of the `constant`, `linear`
+// and `quadratic` coefficients (the ones left out are 0).
+fn parse_attenuation(yaml: &Yaml) -> Result<Attenuation, Box<dyn Error>> {
+    if let Some(name) = yaml.as_str() {
+        return match name {
+            "none" => Ok(Attenuation::None),
+            "inverse_square" => Ok(Attenuation::InverseSquare),
+            _ => Err(Box::new(YamlParsingError::new(
+                "Attenuation needs to be none, inverse_square or a map of coefficients",
+            ))),
+        };
+    }
+
+    let Some(map) = yaml.as_hash() else {
+        return Err(Box::new(YamlParsingError::new(
+            "Attenuation needs to be none, inverse_square or a map of coefficients",
+        )));
+    };
+
+    let mut constant = 0.0;
+    let mut linear = 0.0;
+    let mut quadratic = 0.0;
+
+    for (key, value) in map {
+        match key.as_str().unwrap() {
+            "constant" => constant = parse_f64_from_integer_or_real(value)?,
+            "linear" => linear = parse_f64_from_integer_or_real(value)?,
+            "quadratic" => quadratic = parse_f64_from_integer_or_real(value)?,
+            _ => todo!(),
+        }
+    }
+
+    if constant == 0.0 && linear == 0.0 && quadratic == 0.0 {
+        return Err(Box::new(YamlParsingError::new(
+            "At least one of the attenuation coefficients needs to be set",
+        )));
+    }
+
+    Ok(Attenuation::Coefficients {
+        constant,
+        linear,
+        quadratic,
+    })
+}
+
+// The light goes from the `corner` along `u_edge` and `v_edge`, which are split into `u_steps` and
+// `v_steps` cells.
+pub fn parse_area_light(map: &LinkedHashMap<Yaml, Yaml>) -> Result<AreaLight, Box<dyn Error>> {
+    let mut corner: Option<Point> = None;
+    let mut u_edge: Option<Vector> = None;
+    let mut v_edge: Option<Vector> = None;
+    let mut u_steps = 1;
+    let mut v_steps = 1;
+    let mut intensity: Option<Color> = None;
+    let mut jitter = false;
+
+    for (key, value) in map {
+        match key.as_str().unwrap() {
+            "corner" => corner = Some(parse_point(value.as_vec().unwrap().to_owned())?),
+            "u_edge" => u_edge = Some(parse_vector(value.as_vec().unwrap().to_owned())?),
+            "v_edge" => v_edge = Some(parse_vector(value.as_vec().unwrap().to_owned())?),
+            "u_steps" => u_steps = parse_steps(value)?,
+            "v_steps" => v_steps = parse_steps(value)?,
+            "intensity" => intensity = Some(parse_color(value)?),
+            "jitter" => {
+                jitter = value.as_bool().ok_or_else(|| {
+                    YamlParsingError::new("The jitter of an area light needs to be true or false")
+                })?
+            }
+            _ => todo!(),
+        }
+    }
+
+    let (Some(corner), Some(u_edge), Some(v_edge), Some(intensity)) =
+        (corner, u_edge, v_edge, intensity)
+    else {
+        return Err(Box::new(YamlParsingError::new(
+            "An area light needs a corner, a u_edge, a v_edge and an intensity",
+        )));
+    };
+
+    let mut light = AreaLight::new(corner, u_edge, u_steps, v_edge, v_steps, intensity);
+    light.set_jitter(jitter);
+
+    Ok(light)
+}
+
+fn parse_steps(yaml: &Yaml) -> Result<usize, Box<dyn Error>> {
+    match yaml.as_i64() {
+        Some(steps) if steps > 0 => Ok(steps as usize),
+        _ => Err(Box::new(YamlParsingError::new(
+            "The steps of an area light need to be a whole number greater than zero",
+        ))),
+    }
+}
+
+// The angles of the cones are given in radians, like the field of view of the camera.
+pub fn parse_spot_light(map: &LinkedHashMap<Yaml, Yaml>) -> Result<SpotLight, Box<dyn Error>> {
+    let mut at: Option<Point> = None;
+    let mut direction: Option<Vector> = None;
+    let mut inner_angle: Option<f64> = None;
+    let mut outer_angle: Option<f64> = None;
+    let mut intensity: Option<Color> = None;
+
+    for (key, value) in map {
+        match key.as_str().unwrap() {
+            "at" => at = Some(parse_point(value.as_vec().unwrap().to_owned())?),
+            "direction" => direction = Some(parse_vector(value.as_vec().unwrap().to_owned())?),
+            "inner_angle" => inner_angle = Some(parse_f64_from_integer_or_real(value)?),
+            "outer_angle" => outer_angle = Some(parse_f64_from_integer_or_real(value)?),
+            "intensity" => intensity = Some(parse_color(value)?),
+            _ => todo!(),
+        }
+    }
+
+    let (Some(at), Some(direction), Some(inner_angle), Some(outer_angle), Some(intensity)) =
+        (at, direction, inner_angle, outer_angle, intensity)
+    else {
+        return Err(Box::new(YamlParsingError::new(
+            "A spotlight needs an at, a direction, an inner_angle, an outer_angle and an intensity",
+        )));
+    };
+
+    if inner_angle < 0.0 || outer_angle < inner_angle {
+        return Err(Box::new(YamlParsingError::new(
+            "The inner_angle of a spotlight can't be negative or bigger than its outer_angle",
+        )));
+    }
+
+    Ok(SpotLight::new(
+        intensity,
+        at,
+        direction,
+        inner_angle,
+        outer_angle,
+    ))
+}
+
+// The direction is the way the light travels, so a sun overhead points down.
+pub fn parse_directional_light(
+    map: &LinkedHashMap<Yaml, Yaml>,
+) -> Result<DirectionalLight, Box<dyn Error>> {
+    let mut direction: Option<Vector> = None;
+    let mut intensity: Option<Color> = None;
+
+    for (key, value) in map {
+        match key.as_str().unwrap() {
+            "direction" => direction = Some(parse_vector(value.as_vec().unwrap().to_owned())?),
+            "intensity" => intensity = Some(parse_color(value)?),
+            _ => todo!(),
+        }
+    }
+
+    let (Some(direction), Some(intensity)) = (direction, intensity) else {
+        return Err(Box::new(YamlParsingError::new(
+            "A directional light needs a direction and an intensity",
+        )));
+    };
+
+    Ok(DirectionalLight::new(intensity, direction))
 }
@@ -0,0 +1,410 @@
+use core::fmt;
+use std::error::Error;
+use std::fs::read_to_string;
+use std::path::Path;
+use std::rc::Rc;
+
+use crate::prelude::*;
+use linked_hash_map::LinkedHashMap;
+use yaml_rust::Yaml;
+
+/// Everything we could make sense of in a Wavefront OBJ file.
+///
+/// Faces that come before any `g` statement end up in the default group, the rest are kept in
+/// their named groups in the order they were found.
+pub struct ParsedObj {
+    vertices: Vec<Point>,
+    normals: Vec<Vector>,
+    default_group: Vec<Box<dyn Shape>>,
+    named_groups: LinkedHashMap<String, Vec<Box<dyn Shape>>>,
+    ignored_lines: usize,
+}
+
+impl ParsedObj {
+    /// How many lines in the file were ignored because we didn't recognize them.
+    pub fn ignored_lines(&self) -> usize {
+        self.ignored_lines
+    }
+
+    /// Gives the material to every triangle in the file.
+    pub fn set_material(&mut self, material: &Material) {
+        let named_triangles = self
+            .named_groups
+            .iter_mut()
+            .flat_map(|(_name, group)| group);
+
+        for triangle in self.default_group.iter_mut().chain(named_triangles) {
+            triangle.set_material(material.clone());
+        }
+    }
+
+    /// Puts every triangle in the file into one group. Each named group in the file becomes a
+    /// group of its own inside of that one.
+    pub fn into_group(self) -> Group {
+        let mut group = Group::new();
+
+        for triangle in self.default_group {
+            group.add_child(Rc::from(triangle));
+        }
+
+        for (_name, triangles) in self.named_groups {
+            let mut named_group = Group::new();
+
+            for triangle in triangles {
+                named_group.add_child(Rc::from(triangle));
+            }
+
+            group.add_child(Rc::new(named_group));
+        }
+
+        group
+    }
+}
+
+pub fn parse_obj(text: &str) -> Result<ParsedObj, Box<dyn Error>> {
+    let mut parsed = ParsedObj {
+        vertices: Vec::new(),
+        normals: Vec::new(),
+        default_group: Vec::new(),
+        named_groups: LinkedHashMap::new(),
+        ignored_lines: 0,
+    };
+
+    let mut current_group: Option<String> = None;
+
+    for line in text.lines() {
+        let mut tokens = line.split_whitespace();
+
+        match tokens.next() {
+            Some("v") => match parse_three_values(tokens) {
+                Some([x, y, z]) => parsed.vertices.push(Point::new(x, y, z)),
+                None => parsed.ignored_lines += 1,
+            },
+            Some("vn") => match parse_three_values(tokens) {
+                Some([x, y, z]) => parsed.normals.push(Vector::new(x, y, z)),
+                None => parsed.ignored_lines += 1,
+            },
+            Some("f") => {
+                let triangles = parse_face(tokens, &parsed.vertices, &parsed.normals)?;
+
+                let group = match &current_group {
+                    Some(name) => parsed.named_groups.get_mut(name).unwrap(),
+                    None => &mut parsed.default_group,
+                };
+
+                for triangle in triangles {
+                    group.push(triangle);
+                }
+            }
+            Some("g") => {
+                let name = tokens.collect::<Vec<&str>>().join(" ");
+                if !parsed.named_groups.contains_key(&name) {
+                    parsed.named_groups.insert(name.clone(), Vec::new());
+                }
+                current_group = Some(name);
+            }
+            _ => parsed.ignored_lines += 1,
+        }
+    }
+
+    Ok(parsed)
+}
+
+pub fn parse_obj_file(path: &Path) -> Result<ParsedObj, Box<dyn Error>> {
+    let text = read_to_string(path)?;
+    parse_obj(text.as_str())
+}
+
+/// Creates the group of triangles for an `obj` entry in a scene, the `file` of which is found
+/// relative to the directory of the scene.
+/// The `material`, if there is one, is given to every triangle in the file.
+pub fn parse_obj_from_yaml(
+    map: &LinkedHashMap<Yaml, Yaml>,
+    scene_directory: &Path,
+    material: Option<&Material>,
+) -> Result<Group, Box<dyn Error>> {
+    let file = map
+        .get(&Yaml::String("file".to_string()))
+        .and_then(|file| file.as_str())
+        .ok_or(ObjParsingError::new("An obj needs a file"))?;
+
+    let mut parsed = parse_obj_file(&scene_directory.join(file))?;
+
+    if let Some(material) = material {
+        parsed.set_material(material);
+    }
+
+    Ok(parsed.into_group())
+}
+
+fn parse_three_values<'a>(tokens: impl Iterator<Item = &'a str>) -> Option<[f64; 3]> {
+    let values: Vec<f64> = tokens
+        .take(3)
+        .map(|token| token.parse::<f64>())
+        .collect::<Result<Vec<f64>, _>>()
+        .ok()?;
+
+    values.try_into().ok()
+}
+
+// Faces with more than three vertices are assumed to be convex, and are broken up into a fan of
+// triangles that all share the first vertex.
+fn parse_face<'a>(
+    tokens: impl Iterator<Item = &'a str>,
+    vertices: &[Point],
+    normals: &[Vector],
+) -> Result<Vec<Box<dyn Shape>>, Box<dyn Error>> {
+    let mut face_vertices: Vec<(Point, Option<Vector>)> = Vec::new();
+
+    for token in tokens {
+        // Each vertex is given like "v", "v/vt", "v//vn" or "v/vt/vn", we don't use textures.
+        let mut indexes = token.split('/');
+
+        let vertex = look_up(vertices, indexes.next())?
+            .ok_or(ObjParsingError::new("A face vertex needs an index"))?;
+
+        let _texture = indexes.next();
+        let normal = look_up(normals, indexes.next())?;
+
+        face_vertices.push((vertex, normal));
+    }
+
+    if face_vertices.len() < 3 {
+        return Err(Box::new(ObjParsingError::new(
+            "A face needs at least three vertices",
+        )));
+    }
+
+    let mut triangles: Vec<Box<dyn Shape>> = Vec::new();
+
+    for i in 1..face_vertices.len() - 1 {
+        let (p1, n1) = face_vertices[0];
+        let (p2, n2) = face_vertices[i];
+        let (p3, n3) = face_vertices[i + 1];
+
+        match (n1, n2, n3) {
+            (Some(n1), Some(n2), Some(n3)) => {
+                triangles.push(Box::new(SmoothTriangle::new(p1, p2, p3, n1, n2, n3)))
+            }
+            _ => triangles.push(Box::new(Triangle::new(p1, p2, p3))),
+        }
+    }
+
+    Ok(triangles)
+}
+
+// OBJ indexes start at 1, and negative ones count backwards from the latest value.
+fn look_up<T: Copy>(values: &[T], index: Option<&str>) -> Result<Option<T>, Box<dyn Error>> {
+    let index = match index {
+        Some(index) if !index.is_empty() => index.parse::<i64>()?,
+        _ => return Ok(None),
+    };
+
+    let position = if index < 0 {
+        values.len() as i64 + index
+    } else {
+        index - 1
+    };
+
+    if position < 0 || position >= values.len() as i64 {
+        return Err(Box::new(ObjParsingError::new(
+            format!("Index {} is out of range", index).as_str(),
+        )));
+    }
+
+    Ok(Some(values[position as usize]))
+}
+
+#[derive(Debug)]
+pub struct ObjParsingError {
+    message: String,
+}
+
+impl ObjParsingError {
+    pub fn new(message: &str) -> Self {
+        ObjParsingError {
+            message: message.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ObjParsingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for ObjParsingError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn unrecognized_lines_are_ignored() {
+        let text = "There was a young lady named Bright
+who traveled much faster than light.
+She set out one day
+in a relative way,
+and came back the previous night.";
+
+        let parsed = parse_obj(text).unwrap();
+
+        assert_eq!(parsed.ignored_lines(), 5);
+    }
+
+    #[test]
+    fn vertex_records_are_parsed() {
+        let text = "v -1 1 0
+v -1.0000 0.5000 0.0000
+v 1 0 0
+v 1 1 0";
+
+        let parsed = parse_obj(text).unwrap();
+
+        assert_eq!(parsed.ignored_lines(), 0);
+        assert_eq!(
+            parsed.vertices,
+            vec![
+                Point::new(-1.0, 1.0, 0.0),
+                Point::new(-1.0, 0.5, 0.0),
+                Point::new(1.0, 0.0, 0.0),
+                Point::new(1.0, 1.0, 0.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn vertex_normal_records_are_parsed() {
+        let text = "vn 0 0 1
+vn 0.707 0 -0.707
+vn 1 2 3";
+
+        let parsed = parse_obj(text).unwrap();
+
+        assert_eq!(
+            parsed.normals,
+            vec![
+                Vector::new(0.0, 0.0, 1.0),
+                Vector::new(0.707, 0.0, -0.707),
+                Vector::new(1.0, 2.0, 3.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn triangle_faces_are_parsed() {
+        let text = "v -1 1 0
+v -1 0 0
+v 1 0 0
+v 1 1 0
+
+f 1 2 3
+f 1 3 4";
+
+        let parsed = parse_obj(text).unwrap();
+
+        // That blank line is the only thing that isn't understood.
+        assert_eq!(parsed.ignored_lines(), 1);
+        assert_eq!(parsed.default_group.len(), 2);
+
+        let first = parsed.default_group.first().unwrap();
+        let second = parsed.default_group.get(1).unwrap();
+
+        assert_eq!(first.shape_type(), ShapeType::Triangle);
+        assert_eq!(second.shape_type(), ShapeType::Triangle);
+
+        // The first triangle covers the left half of the square, the second the right.
+        let ray_to_left = Ray::new(Point::new(-0.5, 0.4, -1.0), POSITIVE_Z);
+        let ray_to_right = Ray::new(Point::new(0.5, 0.6, -1.0), POSITIVE_Z);
+        assert_eq!(first.times_of_intersections_with(&ray_to_left), vec![1.0]);
+        assert!(first.times_of_intersections_with(&ray_to_right).is_empty());
+        assert_eq!(second.times_of_intersections_with(&ray_to_right), vec![1.0]);
+    }
+
+    #[test]
+    fn polygons_are_triangulated() {
+        let text = "v -1 1 0
+v -1 0 0
+v 1 0 0
+v 1 1 0
+v 0 2 0
+f 1 2 3 4 5";
+
+        let parsed = parse_obj(text).unwrap();
+
+        assert_eq!(parsed.default_group.len(), 3);
+
+        // The last triangle of the fan is the one at the top, made of the 1st, 4th and 5th points.
+        let top_triangle = parsed.default_group.get(2).unwrap();
+        let ray = Ray::new(Point::new(0.0, 1.5, -1.0), POSITIVE_Z);
+        assert_eq!(top_triangle.times_of_intersections_with(&ray), vec![1.0]);
+    }
+
+    #[test]
+    fn triangles_are_put_in_their_named_groups() {
+        let text = "v -1 1 0
+v -1 0 0
+v 1 0 0
+v 1 1 0
+g FirstGroup
+f 1 2 3
+g SecondGroup
+f 1 3 4";
+
+        let parsed = parse_obj(text).unwrap();
+
+        assert!(parsed.default_group.is_empty());
+        assert_eq!(parsed.named_groups.get("FirstGroup").unwrap().len(), 1);
+        assert_eq!(parsed.named_groups.get("SecondGroup").unwrap().len(), 1);
+        assert_eq!(parsed.into_group().children().len(), 2);
+    }
+
+    #[test]
+    fn faces_with_normals_become_smooth_triangles() {
+        let text = "v 0 1 0
+v -1 0 0
+v 1 0 0
+vn -1 0 0
+vn 1 0 0
+vn 0 1 0
+f 1//3 2//1 3//2
+f 1/0/3 2/102/1 3/14/2";
+
+        let parsed = parse_obj(text).unwrap();
+
+        assert_eq!(parsed.default_group.len(), 2);
+
+        for triangle in &parsed.default_group {
+            assert_eq!(triangle.shape_type(), ShapeType::SmoothTriangle);
+            assert_eq!(
+                triangle.normal_at(ORIGIN, Some((0.0, 0.0))),
+                POSITIVE_Y,
+                "the normal at the first point should be the third normal"
+            );
+        }
+    }
+
+    #[test]
+    fn negative_indexes_count_back_from_the_end() {
+        let text = "v 0 1 0
+v -1 0 0
+v 1 0 0
+f -3 -2 -1";
+
+        let parsed = parse_obj(text).unwrap();
+
+        let triangle = parsed.default_group.first().unwrap();
+        let ray = Ray::new(Point::new(0.0, 0.5, -1.0), POSITIVE_Z);
+        assert_eq!(triangle.times_of_intersections_with(&ray), vec![1.0]);
+    }
+
+    #[test]
+    fn an_index_that_is_out_of_range_is_an_error() {
+        let text = "v 0 1 0
+v -1 0 0
+f 1 2 3";
+
+        assert!(parse_obj(text).is_err());
+    }
+}
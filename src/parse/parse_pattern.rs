@@ -0,0 +1,474 @@
+use std::error::Error;
+use std::path::Path;
+use std::rc::Rc;
+
+use crate::{parse::parse_shape::parse_transform, prelude::*};
+use yaml_rust::Yaml;
+
+use super::parse_little_things::{parse_color, parse_f64_from_integer_or_real, YamlParsingError};
+
+pub fn parse_pattern(
+    yaml: &Yaml,
+    scene_directory: &Path,
+) -> Result<Box<dyn Pattern>, Box<dyn Error>> {
+    if let Some(_map) = yaml.as_hash() {
+        parse_single_pattern(yaml, scene_directory)
+    } else {
+        let mut patterns: Vec<Rc<dyn Pattern>> = Vec::new();
+
+        for node in yaml.as_vec().unwrap() {
+            let pattern = parse_single_pattern(node, scene_directory).unwrap();
+            patterns.push(Rc::from(pattern));
+        }
+
+        Ok(Box::new(BlendedPattern::new(patterns)))
+    }
+}
+
+fn parse_single_pattern(
+    yaml: &Yaml,
+    scene_directory: &Path,
+) -> Result<Box<dyn Pattern>, Box<dyn Error>> {
+    let mut pattern: Option<Box<dyn Pattern>> = None;
+
+    for (key, value) in yaml.as_hash().unwrap() {
+        match key.as_str().unwrap() {
+            "flat" => {
+                let color = parse_color(value)?;
+                pattern = Some(Box::new(FlatPattern::new(color)));
+            }
+            "stripes" => {
+                pattern = Some(parse_transformable_pattern(value, |colors: [Color; 2]| {
+                    Box::new(StripePattern::new(colors[0], colors[1]))
+                })?);
+            }
+            "gradient" if !value["stops"].is_badvalue() => {
+                let gradient = GradientPattern::new_with_ramp(parse_ramp(value)?);
+                pattern = Some(with_transform(Box::new(gradient), value)?);
+            }
+            "gradient" => {
+                pattern = Some(parse_transformable_pattern(value, |colors: [Color; 2]| {
+                    Box::new(GradientPattern::new(colors[0], colors[1]))
+                })?);
+            }
+            "checkers" => {
+                pattern = Some(parse_checkers(value, scene_directory)?);
+            }
+            "rings" if !value["stops"].is_badvalue() => {
+                let rings = RingPattern::new_with_ramp(parse_ramp(value)?);
+                pattern = Some(with_transform(Box::new(rings), value)?);
+            }
+            "rings" => {
+                pattern = Some(parse_transformable_pattern(value, |colors: [Color; 2]| {
+                    Box::new(RingPattern::new(colors[0], colors[1]))
+                })?);
+            }
+            "texture" => {
+                pattern = Some(parse_texture(value, scene_directory)?);
+            }
+            "uv_checkers" => {
+                pattern = Some(parse_uv_checkers(value)?);
+            }
+            "align_check" => {
+                pattern = Some(parse_align_check(value)?);
+            }
+            "perturbed" => {
+                pattern = Some(parse_perturbed(value, scene_directory)?);
+            }
+            "marble" => {
+                let turbulence = parse_turbulence(value)?.unwrap_or(5.0);
+                pattern = Some(parse_noise(value, NoiseMapping::Marble { turbulence }, 4)?);
+            }
+            "wood" => {
+                let turbulence = parse_turbulence(value)?.unwrap_or(0.1);
+                pattern = Some(parse_noise(value, NoiseMapping::Wood { turbulence }, 2)?);
+            }
+            "clouds" => {
+                pattern = Some(parse_noise(value, NoiseMapping::Clouds, 5)?);
+            }
+            "worley" => {
+                pattern = Some(parse_worley(value)?);
+            }
+            "radial_gradient" => {
+                pattern = Some(parse_radial_gradient(value)?);
+            }
+            "spherical_gradient" => {
+                pattern = Some(parse_spherical_gradient(value)?);
+            }
+            "angular_gradient" => {
+                pattern = Some(parse_angular_gradient(value)?);
+            }
+            _ => todo!(),
+        }
+    }
+
+    Ok(pattern.unwrap())
+}
+
+fn parse_checkers(yaml: &Yaml, scene_directory: &Path) -> Result<Box<dyn Pattern>, Box<dyn Error>> {
+    if !yaml["subpatterns"].is_badvalue() {
+        let subpattern_nodes = yaml["subpatterns"].as_vec().unwrap();
+
+        let pattern_a = parse_single_pattern(subpattern_nodes.first().unwrap(), scene_directory)?;
+        let pattern_b = parse_single_pattern(subpattern_nodes.get(1).unwrap(), scene_directory)?;
+
+        Ok(Box::new(Checker3DPattern::new_with_patterns(
+            pattern_a, pattern_b,
+        )))
+    } else {
+        parse_transformable_pattern(yaml, |colors: [Color; 2]| {
+            Box::new(Checker3DPattern::new(colors[0], colors[1]))
+        })
+    }
+}
+
+// Another `pattern` with its points moved up to `scale` away, by noise with a number of `octaves`
+// and an optional `seed`.
+fn parse_perturbed(
+    yaml: &Yaml,
+    scene_directory: &Path,
+) -> Result<Box<dyn Pattern>, Box<dyn Error>> {
+    if yaml["pattern"].is_badvalue() {
+        return Err(Box::new(YamlParsingError::new(
+            "A perturbed pattern needs a pattern to perturb",
+        )));
+    }
+
+    let inner_pattern = parse_pattern(&yaml["pattern"], scene_directory)?;
+    let scale = parse_f64_from_integer_or_real(&yaml["scale"])?;
+    let octaves = parse_octaves(yaml)?.unwrap_or(1);
+
+    let mut pattern = PerturbedPattern::new(inner_pattern, scale, octaves);
+
+    if let Some(seed) = parse_seed(yaml)? {
+        pattern.set_seed(seed);
+    }
+
+    with_transform(Box::new(pattern), yaml)
+}
+
+// Noise through a color ramp with the given mapping, along with optional `octaves` (or the given
+// number of them) and `seed`.
+fn parse_noise(
+    yaml: &Yaml,
+    mapping: NoiseMapping,
+    default_octaves: usize,
+) -> Result<Box<dyn Pattern>, Box<dyn Error>> {
+    let octaves = parse_octaves(yaml)?.unwrap_or(default_octaves);
+    let mut pattern = NoisePattern::new(parse_ramp(yaml)?, mapping, octaves);
+
+    if let Some(seed) = parse_seed(yaml)? {
+        pattern.set_seed(seed);
+    }
+
+    with_transform(Box::new(pattern), yaml)
+}
+
+// Cells with either a color from a `palette` each, or a `distance` (f1, f2 or f2_minus_f1, f1 by
+// default) through a color ramp, along with an optional `seed`.
+fn parse_worley(yaml: &Yaml) -> Result<Box<dyn Pattern>, Box<dyn Error>> {
+    let mut pattern = if let Some(palette_nodes) = yaml["palette"].as_vec() {
+        if palette_nodes.is_empty() {
+            return Err(Box::new(YamlParsingError::new(
+                "A palette needs at least one color",
+            )));
+        }
+
+        let palette = palette_nodes
+            .iter()
+            .map(parse_color)
+            .collect::<Result<Vec<Color>, Box<dyn Error>>>()?;
+
+        WorleyPattern::new_with_palette(palette)
+    } else {
+        let distance = match yaml["distance"].as_str() {
+            None | Some("f1") => WorleyDistance::F1,
+            Some("f2") => WorleyDistance::F2,
+            Some("f2_minus_f1") => WorleyDistance::F2MinusF1,
+            _ => {
+                return Err(Box::new(YamlParsingError::new(
+                    "The distance of a worley pattern needs to be f1, f2 or f2_minus_f1",
+                )))
+            }
+        };
+
+        WorleyPattern::new(distance, parse_ramp(yaml)?)
+    };
+
+    if let Some(seed) = parse_seed(yaml)? {
+        pattern.set_seed(seed);
+    }
+
+    with_transform(Box::new(pattern), yaml)
+}
+
+// Either `stops` as [position, color] pairs, or `colors` spread out evenly, along with an optional
+// `interpolation` (linear, smoothstep or constant).
+fn parse_ramp(yaml: &Yaml) -> Result<ColorRamp, Box<dyn Error>> {
+    let mut ramp = if let Some(stop_nodes) = yaml["stops"].as_vec() {
+        if stop_nodes.is_empty() {
+            return Err(Box::new(YamlParsingError::new(
+                "A color ramp needs at least one stop",
+            )));
+        }
+
+        let stops = stop_nodes
+            .iter()
+            .map(parse_stop)
+            .collect::<Result<Vec<(f64, Color)>, Box<dyn Error>>>()?;
+
+        ColorRamp::from_stops(stops)
+    } else if let Some(color_nodes) = yaml["colors"].as_vec() {
+        if color_nodes.is_empty() {
+            return Err(Box::new(YamlParsingError::new(
+                "A color ramp needs at least one color",
+            )));
+        }
+
+        let colors = color_nodes
+            .iter()
+            .map(parse_color)
+            .collect::<Result<Vec<Color>, Box<dyn Error>>>()?;
+
+        ColorRamp::new(colors)
+    } else {
+        return Err(Box::new(YamlParsingError::new(
+            "A color ramp needs a list of stops or colors",
+        )));
+    };
+
+    if let Some(interpolation) = yaml["interpolation"].as_str() {
+        ramp.set_interpolation(match interpolation {
+            "linear" => RampInterpolation::Linear,
+            "smoothstep" => RampInterpolation::Smoothstep,
+            "constant" => RampInterpolation::Constant,
+            _ => {
+                return Err(Box::new(YamlParsingError::new(
+                    "The interpolation of a color ramp needs to be linear, smoothstep or constant",
+                )))
+            }
+        });
+    }
+
+    Ok(ramp)
+}
+
+fn parse_stop(yaml: &Yaml) -> Result<(f64, Color), Box<dyn Error>> {
+    match yaml.as_vec().map(Vec::as_slice) {
+        Some([position, color]) => Ok((
+            parse_f64_from_integer_or_real(position)?,
+            parse_color(color)?,
+        )),
+        _ => Err(Box::new(YamlParsingError::new(
+            "Each stop of a color ramp needs to be a position and a color",
+        ))),
+    }
+}
+
+fn parse_turbulence(yaml: &Yaml) -> Result<Option<f64>, Box<dyn Error>> {
+    if yaml["turbulence"].is_badvalue() {
+        return Ok(None);
+    }
+
+    Ok(Some(parse_f64_from_integer_or_real(&yaml["turbulence"])?))
+}
+
+fn parse_octaves(yaml: &Yaml) -> Result<Option<usize>, Box<dyn Error>> {
+    if yaml["octaves"].is_badvalue() {
+        return Ok(None);
+    }
+
+    match yaml["octaves"].as_i64() {
+        Some(octaves) if octaves >= 1 => Ok(Some(octaves as usize)),
+        _ => Err(Box::new(YamlParsingError::new(
+            "The octaves of noise need to be a whole number of at least 1",
+        ))),
+    }
+}
+
+fn parse_seed(yaml: &Yaml) -> Result<Option<u64>, Box<dyn Error>> {
+    if yaml["seed"].is_badvalue() {
+        return Ok(None);
+    }
+
+    match yaml["seed"].as_i64() {
+        Some(seed) if seed >= 0 => Ok(Some(seed as u64)),
+        _ => Err(Box::new(YamlParsingError::new(
+            "A seed needs to be a whole number of at least 0",
+        ))),
+    }
+}
+
+fn parse_transformable_pattern<F>(
+    value: &Yaml,
+    constructor: F,
+) -> Result<Box<dyn Pattern>, Box<dyn Error>>
+where
+    F: Fn([Color; 2]) -> Box<dyn Pattern>,
+{
+    let colors = parse_color_pair(value)?;
+
+    with_transform(constructor(colors), value)
+}
+
+// A gradient out from the y axis, with an optional `repeat`.
+fn parse_radial_gradient(yaml: &Yaml) -> Result<Box<dyn Pattern>, Box<dyn Error>> {
+    let mut gradient = RadialGradientPattern::new(parse_ramp(yaml)?);
+
+    if let Some(repeat) = parse_repeat(yaml)? {
+        gradient.set_repeat(repeat);
+    }
+
+    with_transform(Box::new(gradient), yaml)
+}
+
+// A gradient out from the origin, with an optional `repeat`.
+fn parse_spherical_gradient(yaml: &Yaml) -> Result<Box<dyn Pattern>, Box<dyn Error>> {
+    let mut gradient = SphericalGradientPattern::new(parse_ramp(yaml)?);
+
+    if let Some(repeat) = parse_repeat(yaml)? {
+        gradient.set_repeat(repeat);
+    }
+
+    with_transform(Box::new(gradient), yaml)
+}
+
+// A gradient around the y axis, split into an optional number of `sectors`, with an optional
+// `repeat`.
+fn parse_angular_gradient(yaml: &Yaml) -> Result<Box<dyn Pattern>, Box<dyn Error>> {
+    let mut gradient = AngularGradientPattern::new(parse_ramp(yaml)?);
+
+    if !yaml["sectors"].is_badvalue() {
+        match yaml["sectors"].as_i64() {
+            Some(sectors) if sectors >= 1 => gradient.set_sectors(sectors as usize),
+            _ => {
+                return Err(Box::new(YamlParsingError::new(
+                    "The sectors of an angular gradient need to be a whole number of at least 1",
+                )))
+            }
+        }
+    }
+    if let Some(repeat) = parse_repeat(yaml)? {
+        gradient.set_repeat(repeat);
+    }
+
+    with_transform(Box::new(gradient), yaml)
+}
+
+fn parse_repeat(yaml: &Yaml) -> Result<Option<GradientRepeat>, Box<dyn Error>> {
+    match yaml["repeat"].as_str() {
+        None if yaml["repeat"].is_badvalue() => Ok(None),
+        Some("clamp") => Ok(Some(GradientRepeat::Clamp)),
+        Some("repeat") => Ok(Some(GradientRepeat::Repeat)),
+        Some("mirror") => Ok(Some(GradientRepeat::Mirror)),
+        _ => Err(Box::new(YamlParsingError::new(
+            "The repeat of a gradient needs to be clamp, repeat or mirror",
+        ))),
+    }
+}
+
+fn with_transform(
+    mut pattern: Box<dyn Pattern>,
+    yaml: &Yaml,
+) -> Result<Box<dyn Pattern>, Box<dyn Error>> {
+    if !yaml["transform"].is_badvalue() {
+        pattern.set_transform(parse_transform(&yaml["transform"])?);
+    }
+
+    Ok(pattern)
+}
+
+fn parse_color_pair(yaml: &Yaml) -> Result<[Color; 2], Box<dyn Error>> {
+    let value_vec = yaml["colors"].as_vec().unwrap().to_owned();
+
+    let first_color = parse_color(value_vec.first().unwrap())?;
+    let second_color = parse_color(value_vec.get(1).unwrap())?;
+
+    Ok([first_color, second_color])
+}
+
+// An image `file` (found relative to the scene) wrapped around the shape with a `mapping`, along
+// with an optional `filter` (nearest or bilinear) and `wrap` (repeat or clamp).
+fn parse_texture(yaml: &Yaml, scene_directory: &Path) -> Result<Box<dyn Pattern>, Box<dyn Error>> {
+    let Some(file) = yaml["file"].as_str() else {
+        return Err(Box::new(YamlParsingError::new(
+            "A texture needs the file of the image to use",
+        )));
+    };
+
+    let mut texture = ImageTexture::from_file(&scene_directory.join(file))?;
+
+    if let Some(filter) = yaml["filter"].as_str() {
+        texture.set_filter(match filter {
+            "nearest" => TextureFilter::Nearest,
+            "bilinear" => TextureFilter::Bilinear,
+            _ => {
+                return Err(Box::new(YamlParsingError::new(
+                    "The filter of a texture needs to be either nearest or bilinear",
+                )))
+            }
+        });
+    }
+
+    if let Some(wrap) = yaml["wrap"].as_str() {
+        texture.set_wrap(match wrap {
+            "repeat" => TextureWrap::Repeat,
+            "clamp" => TextureWrap::Clamp,
+            _ => {
+                return Err(Box::new(YamlParsingError::new(
+                    "The wrap of a texture needs to be either repeat or clamp",
+                )))
+            }
+        });
+    }
+
+    map_uv_pattern(Box::new(texture), yaml)
+}
+
+// A checkerboard with `width` squares across and `height` squares up, put on with a `mapping`.
+fn parse_uv_checkers(yaml: &Yaml) -> Result<Box<dyn Pattern>, Box<dyn Error>> {
+    let width = parse_f64_from_integer_or_real(&yaml["width"])?;
+    let height = parse_f64_from_integer_or_real(&yaml["height"])?;
+    let colors = parse_color_pair(yaml)?;
+
+    map_uv_pattern(
+        Box::new(UvCheckersPattern::new(width, height, colors[0], colors[1])),
+        yaml,
+    )
+}
+
+// A `main` color with its own color in each corner, put on with a `mapping`.
+fn parse_align_check(yaml: &Yaml) -> Result<Box<dyn Pattern>, Box<dyn Error>> {
+    let colors = &yaml["colors"];
+
+    let pattern = AlignCheckPattern::new(
+        parse_color(&colors["main"])?,
+        parse_color(&colors["upper_left"])?,
+        parse_color(&colors["upper_right"])?,
+        parse_color(&colors["bottom_left"])?,
+        parse_color(&colors["bottom_right"])?,
+    );
+
+    map_uv_pattern(Box::new(pattern), yaml)
+}
+
+fn map_uv_pattern(
+    uv_pattern: Box<dyn UvPattern>,
+    yaml: &Yaml,
+) -> Result<Box<dyn Pattern>, Box<dyn Error>> {
+    let mapping = parse_mapping(&yaml["mapping"])?;
+
+    with_transform(Box::new(TextureMapPattern::new(uv_pattern, mapping)), yaml)
+}
+
+fn parse_mapping(yaml: &Yaml) -> Result<UvMapping, Box<dyn Error>> {
+    match yaml.as_str() {
+        Some("planar") => Ok(UvMapping::Planar),
+        Some("spherical") => Ok(UvMapping::Spherical),
+        Some("cylindrical") => Ok(UvMapping::Cylindrical),
+        Some("cube") => Ok(UvMapping::Cube),
+        _ => Err(Box::new(YamlParsingError::new(
+            "The mapping needs to be planar, spherical, cylindrical or cube",
+        ))),
+    }
+}
@@ -1,46 +1,288 @@
 use std::error::Error;
+use std::path::Path;
 use std::rc::Rc;
 
-use crate::{parse::parse_little_things::parse_values, prelude::*};
+use crate::{
+    parse::{
+        parse_little_things::parse_values, parse_obj::parse_obj_from_yaml,
+        parse_pattern::parse_pattern,
+    },
+    prelude::*,
+};
 use linked_hash_map::LinkedHashMap;
 use yaml_rust::Yaml;
 
-use super::parse_little_things::{parse_color, parse_f64_from_integer_or_real};
+use super::parse_little_things::{
+    parse_f64_from_integer_or_real, parse_point, parse_vector, YamlParsingError,
+};
 
 pub fn parse_shape(
     map: Option<&LinkedHashMap<Yaml, Yaml>>,
     shape_name: &str,
+    scene_directory: &Path,
 ) -> Result<Rc<dyn Shape>, Box<dyn Error>> {
+    Ok(Rc::from(parse_owned_shape(
+        map,
+        shape_name,
+        scene_directory,
+        None,
+    )?))
+}
+
+// The `inherited_material` is the one given to a group or CSG shape that this shape is inside of,
+// which it takes unless it's been given a material of its own. It has to be handed down while parsing, since the shapes
+// can't be changed anymore once they're shared with their group.
+fn parse_owned_shape(
+    map: Option<&LinkedHashMap<Yaml, Yaml>>,
+    shape_name: &str,
+    scene_directory: &Path,
+    inherited_material: Option<&Material>,
+) -> Result<Box<dyn Shape>, Box<dyn Error>> {
     let mut given_material: Option<Material> = None;
     let mut given_transform: Option<Transform> = None;
+    let mut casts_shadow = true;
+    let mut visible_to_camera = true;
+    let mut visible_in_reflections = true;
+
+    if let Some(m) = map {
+        for (key, value) in m {
+            match key.as_str().unwrap() {
+                "material" => given_material = Some(parse_material(value, scene_directory)?),
+                "transform" => given_transform = Some(parse_transform(value)?),
+                "casts_shadow" => casts_shadow = parse_flag(value, "casts_shadow")?,
+                "visible_to_camera" => visible_to_camera = parse_flag(value, "visible_to_camera")?,
+                "visible_in_reflections" => {
+                    visible_in_reflections = parse_flag(value, "visible_in_reflections")?
+                }
+                // These are taken care of when the shape itself is created.
+                "minimum" | "maximum" | "closed" | "points" | "normals" | "children" | "file"
+                | "operation" | "left" | "right" | "major_radius" | "minor_radius" => {}
+                _ => todo!(),
+            }
+        }
+    }
+
+    let material = given_material.or_else(|| inherited_material.cloned());
 
     let mut shape: Box<dyn Shape> = match shape_name {
         "sphere" => Box::new(Sphere::new()),
         "plane" => Box::new(Plane::new()),
+        "cube" => Box::new(Cube::new()),
+        "cylinder" => {
+            let (minimum, maximum, closed) = parse_truncation(map)?;
+            Box::new(Cylinder::new_truncated(minimum, maximum, closed))
+        }
+        "cone" => {
+            let (minimum, maximum, closed) = parse_truncation(map)?;
+            Box::new(Cone::new_truncated(minimum, maximum, closed))
+        }
+        "triangle" => parse_triangle(map)?,
+        "torus" => Box::new(parse_torus(map)?),
+        "group" => Box::new(parse_group(map, scene_directory, material.as_ref())?),
+        "obj" => Box::new(parse_obj_from_yaml(
+            map.ok_or(YamlParsingError::new("An obj needs a file"))?,
+            scene_directory,
+            material.as_ref(),
+        )?),
+        "csg" => Box::new(parse_csg(map, scene_directory, material.as_ref())?),
         _ => todo!(),
     };
 
+    if let Some(material) = material {
+        shape.set_material(material);
+    }
+    if let Some(transform) = given_transform {
+        shape.set_transform(transform);
+    }
+
+    shape.set_visibility(Visibility::new(
+        casts_shadow,
+        visible_to_camera,
+        visible_in_reflections,
+    ));
+
+    Ok(shape)
+}
+
+fn parse_flag(yaml: &Yaml, name: &str) -> Result<bool, Box<dyn Error>> {
+    yaml.as_bool().ok_or_else(|| {
+        Box::new(YamlParsingError::new(&format!(
+            "The {} of a shape needs to be true or false",
+            name
+        ))) as Box<dyn Error>
+    })
+}
+
+// Gets the `minimum`, `maximum` and `closed` values shared by cylinders and cones, defaulting to an
+// infinite, open shape.
+fn parse_truncation(
+    map: Option<&LinkedHashMap<Yaml, Yaml>>,
+) -> Result<(f64, f64, bool), Box<dyn Error>> {
+    let mut minimum = f64::NEG_INFINITY;
+    let mut maximum = f64::INFINITY;
+    let mut closed = false;
+
     if let Some(m) = map {
         for (key, value) in m {
             match key.as_str().unwrap() {
-                "material" => given_material = Some(parse_material(value)?),
-                "transform" => given_transform = Some(parse_transform(value)?),
-                _ => todo!(),
+                "minimum" => minimum = parse_f64_from_integer_or_real(value)?,
+                "maximum" => maximum = parse_f64_from_integer_or_real(value)?,
+                "closed" => closed = value.as_bool().unwrap(),
+                _ => {}
             }
         }
+    }
 
-        if let Some(material) = given_material {
-            shape.set_material(material);
+    Ok((minimum, maximum, closed))
+}
+
+// A torus defaults to a major radius of 1 and a minor radius of 0.25, like a ring donut.
+fn parse_torus(map: Option<&LinkedHashMap<Yaml, Yaml>>) -> Result<Torus, Box<dyn Error>> {
+    let mut major_radius = 1.0;
+    let mut minor_radius = 0.25;
+
+    if let Some(m) = map {
+        for (key, value) in m {
+            match key.as_str().unwrap() {
+                "major_radius" => major_radius = parse_f64_from_integer_or_real(value)?,
+                "minor_radius" => minor_radius = parse_f64_from_integer_or_real(value)?,
+                _ => {}
+            }
         }
-        if let Some(transform) = given_transform {
-            shape.set_transform(transform);
+    }
+
+    if minor_radius <= 0.0 || major_radius <= 0.0 {
+        return Err(Box::new(YamlParsingError::new(
+            "The radii of a torus need to be greater than zero",
+        )));
+    }
+
+    Ok(Torus::new(major_radius, minor_radius))
+}
+
+// Each of the `children` of a group is given just like a shape at the top level of a scene.
+fn parse_group(
+    map: Option<&LinkedHashMap<Yaml, Yaml>>,
+    scene_directory: &Path,
+    material: Option<&Material>,
+) -> Result<Group, Box<dyn Error>> {
+    let mut group = Group::new();
+
+    let children = map.and_then(|m| m.get(&Yaml::String("children".to_string())));
+
+    if let Some(child_nodes) = children.and_then(|children| children.as_vec()) {
+        for child_node in child_nodes {
+            for (key, value) in child_node.as_hash().unwrap() {
+                let child = parse_owned_shape(
+                    value.as_hash(),
+                    key.as_str().unwrap(),
+                    scene_directory,
+                    material,
+                )?;
+                group.add_child(Rc::from(child));
+            }
         }
     }
 
-    Ok(Rc::from(shape))
+    Ok(group)
+}
+
+// The `left` and `right` of a CSG shape are each given just like a shape at the top level of a scene.
+fn parse_csg(
+    map: Option<&LinkedHashMap<Yaml, Yaml>>,
+    scene_directory: &Path,
+    material: Option<&Material>,
+) -> Result<Csg, Box<dyn Error>> {
+    let map = map.ok_or(YamlParsingError::new(
+        "A CSG shape needs an operation, a left and a right",
+    ))?;
+    let operation = match map
+        .get(&Yaml::String("operation".to_string()))
+        .and_then(|operation| operation.as_str())
+    {
+        Some("union") => CsgOperation::Union,
+        Some("intersection") => CsgOperation::Intersection,
+        Some("difference") => CsgOperation::Difference,
+        _ => {
+            return Err(Box::new(YamlParsingError::new(
+                "A CSG shape needs an operation of union, intersection or difference",
+            )))
+        }
+    };
+
+    let parse_side = |side: &str| -> Result<Rc<dyn Shape>, Box<dyn Error>> {
+        let side_map = map
+            .get(&Yaml::String(side.to_string()))
+            .and_then(|side| side.as_hash())
+            .ok_or(YamlParsingError::new(
+                "A CSG shape needs a left and a right",
+            ))?;
+
+        let (key, value) = side_map.iter().next().ok_or(YamlParsingError::new(
+            "A CSG shape needs a left and a right",
+        ))?;
+
+        let shape = parse_owned_shape(
+            value.as_hash(),
+            key.as_str().unwrap(),
+            scene_directory,
+            material,
+        )?;
+
+        Ok(Rc::from(shape))
+    };
+
+    let left = parse_side("left")?;
+    let right = parse_side("right")?;
+
+    Ok(Csg::new(operation, left, right))
 }
 
-fn parse_material(yaml: &Yaml) -> Result<Material, Box<dyn Error>> {
+// A triangle given `normals` for each of its points will be a smooth one.
+fn parse_triangle(
+    map: Option<&LinkedHashMap<Yaml, Yaml>>,
+) -> Result<Box<dyn Shape>, Box<dyn Error>> {
+    let map = map.ok_or(YamlParsingError::new("A triangle needs three points"))?;
+    let points_yaml = map
+        .get(&Yaml::String("points".to_string()))
+        .and_then(|points| points.as_vec())
+        .ok_or(YamlParsingError::new("A triangle needs three points"))?;
+
+    let mut points = Vec::new();
+    for point_yaml in points_yaml {
+        points.push(parse_point(point_yaml.as_vec().unwrap().to_owned())?);
+    }
+
+    if points.len() != 3 {
+        return Err(Box::new(YamlParsingError::new(
+            "A triangle needs three points",
+        )));
+    }
+
+    if let Some(normals_yaml) = map
+        .get(&Yaml::String("normals".to_string()))
+        .and_then(|normals| normals.as_vec())
+    {
+        let mut normals = Vec::new();
+        for normal_yaml in normals_yaml {
+            normals.push(parse_vector(normal_yaml.as_vec().unwrap().to_owned())?);
+        }
+
+        if normals.len() != 3 {
+            return Err(Box::new(YamlParsingError::new(
+                "A smooth triangle needs three normals",
+            )));
+        }
+
+        return Ok(Box::new(SmoothTriangle::new(
+            points[0], points[1], points[2], normals[0], normals[1], normals[2],
+        )));
+    }
+
+    Ok(Box::new(Triangle::new(points[0], points[1], points[2])))
+}
+
+pub fn parse_material(yaml: &Yaml, scene_directory: &Path) -> Result<Material, Box<dyn Error>> {
     let map = yaml.as_hash().unwrap();
 
     let mut material_builder = MaterialBuilder::new();
@@ -48,9 +290,7 @@ fn parse_material(yaml: &Yaml) -> Result<Material, Box<dyn Error>> {
     for (key, value) in map {
         match key.as_str().unwrap() {
             "pattern" => {
-                if let Ok(pattern) = parse_pattern(value) {
-                    material_builder = material_builder.pattern(pattern);
-                }
+                material_builder = material_builder.pattern(parse_pattern(value, scene_directory)?);
             }
             "diffuse" => {
                 if let Ok(diffuse) = parse_f64_from_integer_or_real(value) {
@@ -87,119 +327,34 @@ fn parse_material(yaml: &Yaml) -> Result<Material, Box<dyn Error>> {
                     material_builder = material_builder.refractive_index(refractive_index);
                 }
             }
-            _ => todo!(),
-        }
-    }
-
-    Ok(material_builder.build())
-}
-
-fn parse_pattern(yaml: &Yaml) -> Result<Box<dyn Pattern>, Box<dyn Error>> {
-    if let Some(_map) = yaml.as_hash() {
-        parse_single_pattern(yaml)
-    } else {
-        let mut patterns: Vec<Rc<dyn Pattern>> = Vec::new();
-
-        for node in yaml.as_vec().unwrap() {
-            let pattern = parse_single_pattern(node).unwrap();
-            patterns.push(Rc::from(pattern));
-        }
-
-        Ok(Box::new(BlendedPattern::new(patterns)))
-    }
-}
-
-fn parse_single_pattern(yaml: &Yaml) -> Result<Box<dyn Pattern>, Box<dyn Error>> {
-    let mut pattern: Option<Box<dyn Pattern>> = None;
-
-    for (key, value) in yaml.as_hash().unwrap() {
-        match key.as_str().unwrap() {
-            "flat" => {
-                let color = parse_color(value)?;
-                pattern = Some(Box::new(FlatPattern::new(color)));
-            }
-            "stripes" => {
-                pattern = Some(parse_transformable_pattern(value, |colors: [Color; 2]| {
-                    Box::new(StripePattern::new(colors[0], colors[1]))
-                })?);
-            }
-            "gradient" => {
-                pattern = Some(parse_transformable_pattern(value, |colors: [Color; 2]| {
-                    Box::new(GradientPattern::new(colors[0], colors[1]))
-                })?);
+            "pattern_space" => {
+                let pattern_space = match value.as_str() {
+                    Some("object") => PatternSpace::Object,
+                    Some("world") => PatternSpace::World,
+                    _ => {
+                        return Err(Box::new(YamlParsingError::new(
+                            "The pattern space needs to be either object or world",
+                        )))
+                    }
+                };
+                material_builder = material_builder.pattern_space(pattern_space);
             }
-            "checkers" => {
-                pattern = Some(parse_checkers(value)?);
-            }
-            "rings" => {
-                pattern = Some(parse_transformable_pattern(value, |colors: [Color; 2]| {
-                    Box::new(RingPattern::new(colors[0], colors[1]))
-                })?);
+            "opaque_shadow" => {
+                let Some(opaque_shadow) = value.as_bool() else {
+                    return Err(Box::new(YamlParsingError::new(
+                        "The opaque_shadow of a material needs to be true or false",
+                    )));
+                };
+                material_builder = material_builder.opaque_shadow(opaque_shadow);
             }
             _ => todo!(),
         }
     }
 
-    Ok(pattern.unwrap())
-}
-
-fn parse_checkers(yaml: &Yaml) -> Result<Box<dyn Pattern>, Box<dyn Error>> {
-    if !yaml["subpatterns"].is_badvalue() {
-        let subpattern_nodes = yaml["subpatterns"].as_vec().unwrap();
-
-        let pattern_a = parse_single_pattern(subpattern_nodes.get(0).unwrap())?;
-        let pattern_b = parse_single_pattern(subpattern_nodes.get(1).unwrap())?;
-
-        Ok(Box::new(Checker3DPattern::new_with_patterns(
-            pattern_a, pattern_b,
-        )))
-    } else {
-        parse_transformable_pattern(yaml, |colors: [Color; 2]| {
-            Box::new(Checker3DPattern::new(colors[0], colors[1]))
-        })
-    }
-}
-
-fn parse_transformable_pattern<F>(
-    value: &Yaml,
-    constructor: F,
-) -> Result<Box<dyn Pattern>, Box<dyn Error>>
-where
-    F: Fn([Color; 2]) -> Box<dyn Pattern>,
-{
-    let (colors, transform) = parse_pattern_values(value)?;
-
-    let mut pattern = constructor(colors);
-
-    if let Some(t) = transform {
-        pattern.set_transform(t)
-    }
-
-    Ok(pattern)
-}
-
-fn parse_pattern_values(value: &Yaml) -> Result<([Color; 2], Option<Transform>), Box<dyn Error>> {
-    let colors = parse_color_pair(value)?;
-
-    let mut transform: Option<Transform> = None;
-    let transform_yaml = &value["transform"];
-    if !transform_yaml.is_badvalue() {
-        transform = parse_transform(&value["transform"]).ok();
-    }
-
-    Ok((colors, transform))
-}
-
-fn parse_color_pair(yaml: &Yaml) -> Result<[Color; 2], Box<dyn Error>> {
-    let value_vec = yaml["colors"].as_vec().unwrap().to_owned();
-
-    let first_color = parse_color(value_vec.get(0).unwrap())?;
-    let second_color = parse_color(value_vec.get(1).unwrap())?;
-
-    Ok([first_color, second_color])
+    Ok(material_builder.build())
 }
 
-fn parse_transform(yaml: &Yaml) -> Result<Transform, Box<dyn Error>> {
+pub fn parse_transform(yaml: &Yaml) -> Result<Transform, Box<dyn Error>> {
     let nodes = yaml.as_vec().unwrap();
 
     let mut transform = Transform::new(IDENTITY_MATRIX);
@@ -1,9 +1,13 @@
-use std::{error::Error, fs::read_to_string};
+use std::{error::Error, fs::read_to_string, path::Path, rc::Rc};
 
 use yaml_rust::YamlLoader;
 
 use crate::{
-    parse::{parse_camera::parse_camera, parse_light::parse_light, parse_shape::parse_shape},
+    parse::{
+        parse_camera::parse_camera,
+        parse_light::{parse_area_light, parse_directional_light, parse_light, parse_spot_light},
+        parse_shape::parse_shape,
+    },
     prelude::*,
 };
 
@@ -18,9 +22,13 @@ pub fn parse_scene_from_yaml(file_path: &str) -> Result<(World, Camera), Box<dyn
         .unwrap()
         .to_owned();
 
+    // Any other files a scene refers to are found relative to the scene itself.
+    let scene_directory = Path::new(file_path).parent().unwrap_or(Path::new(""));
+
     let mut world = World::new();
     let mut camera = Camera::new(100, 100, 100.0);
     let mut background = BLACK;
+    let mut lights: Vec<Rc<dyn Light>> = Vec::new();
 
     for node in nodes {
         match node {
@@ -28,10 +36,22 @@ pub fn parse_scene_from_yaml(file_path: &str) -> Result<(World, Camera), Box<dyn
                 for (key, value) in h {
                     match key.as_str().unwrap() {
                         "camera" => camera = parse_camera(value.as_hash().unwrap().clone())?,
-                        "light" => world.set_light(parse_light(value.as_hash().unwrap())?),
-                        "sphere" | "plane" => {
-                            world.add_shape(parse_shape(value.as_hash(), key.as_str().unwrap())?)
+                        "light" => lights.push(Rc::new(parse_light(value.as_hash().unwrap())?)),
+                        "area_light" => {
+                            lights.push(Rc::new(parse_area_light(value.as_hash().unwrap())?))
+                        }
+                        "spotlight" => {
+                            lights.push(Rc::new(parse_spot_light(value.as_hash().unwrap())?))
                         }
+                        "directional_light" => {
+                            lights.push(Rc::new(parse_directional_light(value.as_hash().unwrap())?))
+                        }
+                        "sphere" | "plane" | "cube" | "cylinder" | "cone" | "triangle"
+                        | "group" | "obj" | "csg" | "torus" => world.add_shape(parse_shape(
+                            value.as_hash(),
+                            key.as_str().unwrap(),
+                            scene_directory,
+                        )?),
                         "background" => background = parse_color(&value).unwrap(),
                         _ => todo!(),
                     }
@@ -45,6 +65,11 @@ pub fn parse_scene_from_yaml(file_path: &str) -> Result<(World, Camera), Box<dyn
 
     world.set_background(background);
 
+    // Without any lights given, the world just keeps its default one.
+    if !lights.is_empty() {
+        world.set_lights(lights);
+    }
+
     return Ok((world, camera));
 }
 
@@ -58,9 +83,10 @@ mod test {
         let (world, _camera) =
             parse_scene_from_yaml("tests/scenes/scene_with_sphere.yaml").unwrap();
 
-        let light = world.light();
-        assert_eq!(light.intensity().to_owned(), Color::new(1.0, 1.0, 1.0));
-        assert_eq!(light.position().to_owned(), Point::new(-10.0, 10.0, -10.0));
+        assert_eq!(world.lights().len(), 1);
+        let expected_light =
+            PointLight::new(Color::new(1.0, 1.0, 1.0), Point::new(-10.0, 10.0, -10.0));
+        assert_eq!(&*world.lights()[0], &expected_light as &dyn Light);
     }
 
     #[test]
@@ -126,6 +152,712 @@ mod test {
         assert_eq!(*plane.transform(), expected_transform);
     }
 
+    #[test]
+    fn a_cube_is_correctly_parsed() {
+        let (world, _camera) = parse_scene_from_yaml("tests/scenes/scene_with_cube.yaml").unwrap();
+
+        let shapes = world.shapes().to_owned();
+        assert_eq!(shapes.len(), 1);
+
+        let cube = shapes.first().unwrap().to_owned();
+        assert_eq!(cube.shape_type(), ShapeType::Cube);
+
+        let expected_material = MaterialBuilder::new()
+            .diffuse(0.6)
+            .specular(0.1)
+            .flat_color(Color::new(0.8, 0.5, 0.2))
+            .build();
+        assert_eq!(*cube.material(), expected_material);
+
+        let expected_transform = Transform::translation(0.0, 1.0, 0.0)
+            * Transform::y_rotation(0.5)
+            * Transform::scaling(0.5, 1.0, 0.5);
+        assert_eq!(*cube.transform(), expected_transform);
+    }
+
+    #[test]
+    fn a_truncated_and_closed_cylinder_is_correctly_parsed() {
+        let (world, _camera) =
+            parse_scene_from_yaml("tests/scenes/scene_with_cylinder.yaml").unwrap();
+
+        let shapes = world.shapes().to_owned();
+        assert_eq!(shapes.len(), 2);
+
+        let cylinder = shapes.first().unwrap().to_owned();
+        assert_eq!(cylinder.shape_type(), ShapeType::Cylinder);
+
+        let expected_material = MaterialBuilder::new()
+            .diffuse(0.8)
+            .specular(0.4)
+            .flat_color(Color::new(0.2, 0.4, 0.9))
+            .build();
+        assert_eq!(*cylinder.material(), expected_material);
+
+        let expected_transform =
+            Transform::translation(0.5, 0.0, 0.0) * Transform::scaling(0.5, 1.0, 0.5);
+        assert_eq!(*cylinder.transform(), expected_transform);
+
+        // A ray coming straight down the middle should hit the top cap...
+        let ray = Ray::new(Point::new(0.5, 5.0, 0.0), NEGATIVE_Y);
+        let times = cylinder.times_of_intersections_with(&ray);
+        assert_eq!(times.len(), 2);
+        assert!(times.iter().any(|t| close_enough(t, &3.5)));
+        assert!(times.iter().any(|t| close_enough(t, &5.0)));
+    }
+
+    #[test]
+    fn a_cylinder_with_no_limits_is_infinite() {
+        let (world, _camera) =
+            parse_scene_from_yaml("tests/scenes/scene_with_cylinder.yaml").unwrap();
+
+        let cylinder = world.shapes().get(1).unwrap();
+
+        // So there's nothing for this ray to hit...
+        let ray = Ray::new(Point::new(-2.0, 5.0, 3.0), NEGATIVE_Y);
+        assert!(cylinder.times_of_intersections_with(&ray).is_empty());
+    }
+
+    #[test]
+    fn a_cone_is_correctly_parsed() {
+        let (world, _camera) = parse_scene_from_yaml("tests/scenes/scene_with_cone.yaml").unwrap();
+
+        let cone = world.shapes().first().unwrap();
+        assert_eq!(cone.shape_type(), ShapeType::Cone);
+
+        let expected_material = MaterialBuilder::new()
+            .diffuse(0.7)
+            .specular(0.3)
+            .flat_color(Color::new(1.0, 0.8, 0.1))
+            .build();
+        assert_eq!(*cone.material(), expected_material);
+        assert_eq!(*cone.transform(), Transform::translation(0.0, 2.0, 0.0));
+
+        // This ray should pass through the cone's wall and out of its closed bottom.
+        let ray = Ray::new(Point::new(0.0, 2.0, -0.5), NEGATIVE_Y);
+        let times = cone.times_of_intersections_with(&ray);
+        assert_eq!(times.len(), 2);
+        assert!(times.iter().any(|t| close_enough(t, &0.5)));
+        assert!(times.iter().any(|t| close_enough(t, &1.0)));
+    }
+
+    #[test]
+    fn triangles_are_correctly_parsed() {
+        let (world, _camera) =
+            parse_scene_from_yaml("tests/scenes/scene_with_triangles.yaml").unwrap();
+
+        let shapes = world.shapes().to_owned();
+        assert_eq!(shapes.len(), 2);
+
+        let flat_triangle = shapes.first().unwrap();
+        assert_eq!(flat_triangle.shape_type(), ShapeType::Triangle);
+        assert_eq!(
+            *flat_triangle.material(),
+            MaterialBuilder::new()
+                .flat_color(Color::new(0.9, 0.2, 0.2))
+                .build()
+        );
+
+        let ray = Ray::new(Point::new(0.0, 0.5, -2.0), POSITIVE_Z);
+        assert_eq!(flat_triangle.times_of_intersections_with(&ray), vec![2.0]);
+
+        let smooth_triangle = shapes.get(1).unwrap();
+        assert_eq!(smooth_triangle.shape_type(), ShapeType::SmoothTriangle);
+        assert_eq!(
+            *smooth_triangle.transform(),
+            Transform::translation(0.0, 0.5, 0.0)
+        );
+    }
+
+    #[test]
+    fn an_obj_file_can_be_referenced_by_a_scene() {
+        let (world, _camera) = parse_scene_from_yaml("tests/scenes/scene_with_obj.yaml").unwrap();
+
+        let shapes = world.shapes().to_owned();
+        assert_eq!(shapes.len(), 1);
+
+        let obj = shapes.first().unwrap();
+        assert_eq!(obj.shape_type(), ShapeType::Group);
+        assert_eq!(
+            *obj.transform(),
+            Transform::translation(0.0, 1.0, 0.0) * Transform::scaling(0.5, 0.5, 0.5)
+        );
+
+        let expected_material = MaterialBuilder::new()
+            .flat_color(Color::new(0.3, 0.6, 0.9))
+            .diffuse(0.8)
+            .build();
+
+        // The file has a triangle with a square behind it, and this ray goes through both.
+        let ray = Ray::new(Point::new(0.1, 1.2, -5.0), POSITIVE_Z);
+        let intersections = Intersection::of(obj, &ray);
+        assert_eq!(intersections.len(), 2);
+
+        for intersection in intersections {
+            assert_eq!(intersection.object().shape_type(), ShapeType::Triangle);
+            assert_eq!(*intersection.material(), expected_material);
+        }
+    }
+
+    #[test]
+    fn nested_groups_are_correctly_parsed() {
+        let (world, _camera) = parse_scene_from_yaml("tests/scenes/scene_with_group.yaml").unwrap();
+
+        let shapes = world.shapes().to_owned();
+        assert_eq!(shapes.len(), 1);
+
+        let group = shapes.first().unwrap();
+        assert_eq!(group.shape_type(), ShapeType::Group);
+        assert_eq!(*group.transform(), Transform::translation(0.0, 1.0, 0.0));
+
+        let expected_material = MaterialBuilder::new()
+            .flat_color(Color::new(0.2, 0.9, 0.4))
+            .diffuse(0.6)
+            .build();
+
+        // A ray along the x axis of the group goes through the first sphere, the cube, and the
+        // sphere in the inner group, which has been scaled down to end up at x = 2.
+        let ray = Ray::new(Point::new(-10.0, 1.0, 0.0), POSITIVE_X);
+        let mut intersections = Intersection::of(group, &ray);
+        intersections.sort_by(|a, b| a.t().total_cmp(b.t()));
+
+        let expected_types = [
+            ShapeType::Sphere,
+            ShapeType::Sphere,
+            ShapeType::Cube,
+            ShapeType::Cube,
+            ShapeType::Sphere,
+            ShapeType::Sphere,
+        ];
+        let expected_times = [7.0, 9.0, 9.5, 10.5, 11.5, 12.5];
+
+        assert_eq!(intersections.len(), 6);
+        for (i, intersection) in intersections.iter().enumerate() {
+            assert_eq!(intersection.object().shape_type(), expected_types[i]);
+            assert!(close_enough(intersection.t(), &expected_times[i]));
+            assert_eq!(*intersection.material(), expected_material);
+        }
+    }
+
+    #[test]
+    fn the_material_of_a_group_only_goes_to_children_without_their_own() {
+        let (world, _camera) =
+            parse_scene_from_yaml("tests/scenes/scene_with_group_materials.yaml").unwrap();
+
+        let group = world.shapes().first().unwrap();
+
+        let group_material = MaterialBuilder::new()
+            .flat_color(Color::new(0.2, 0.9, 0.4))
+            .build();
+        let sphere_material = MaterialBuilder::new()
+            .flat_color(Color::new(0.9, 0.1, 0.1))
+            .diffuse(0.5)
+            .build();
+        let inner_group_material = MaterialBuilder::new()
+            .flat_color(Color::new(0.1, 0.1, 0.9))
+            .build();
+
+        // A ray along the x axis goes through the sphere without a material, the sphere with its
+        // own, and the cube in the inner group.
+        let ray = Ray::new(Point::new(-10.0, 0.0, 0.0), POSITIVE_X);
+        let mut intersections = Intersection::of(group, &ray);
+        intersections.sort_by(|a, b| a.t().total_cmp(b.t()));
+
+        let expected_materials = [
+            &group_material,
+            &group_material,
+            &sphere_material,
+            &sphere_material,
+            &inner_group_material,
+            &inner_group_material,
+        ];
+
+        assert_eq!(intersections.len(), 6);
+        for (intersection, expected_material) in intersections.iter().zip(expected_materials) {
+            assert_eq!(*intersection.material(), *expected_material);
+        }
+    }
+
+    #[test]
+    fn a_csg_shape_is_correctly_parsed() {
+        let (world, _camera) = parse_scene_from_yaml("tests/scenes/scene_with_csg.yaml").unwrap();
+
+        let csg = world.shapes().first().unwrap();
+        assert_eq!(csg.shape_type(), ShapeType::Csg);
+        assert_eq!(*csg.transform(), Transform::translation(0.0, 1.0, 0.0));
+
+        // The front of the cube is where the sphere was cut out, so the first thing the ray hits is
+        // the inside of the hole, and then the back of the cube.
+        let ray = Ray::new(Point::new(0.0, 1.0, -5.0), POSITIVE_Z);
+        let intersections = Intersection::of(csg, &ray);
+
+        assert_eq!(intersections.len(), 2);
+
+        assert_eq!(intersections[0].object().shape_type(), ShapeType::Sphere);
+        assert!(close_enough(intersections[0].t(), &4.3));
+        assert_eq!(
+            *intersections[0].material(),
+            MaterialBuilder::new()
+                .flat_color(Color::new(0.1, 0.1, 0.1))
+                .build()
+        );
+
+        assert_eq!(intersections[1].object().shape_type(), ShapeType::Cube);
+        assert!(close_enough(intersections[1].t(), &6.0));
+    }
+
+    #[test]
+    fn a_torus_is_correctly_parsed() {
+        let (world, _camera) = parse_scene_from_yaml("tests/scenes/scene_with_torus.yaml").unwrap();
+
+        let torus = world.shapes().first().unwrap();
+        assert_eq!(torus.shape_type(), ShapeType::Torus);
+        assert_eq!(*torus.transform(), Transform::translation(0.0, 1.0, 0.0));
+        assert_eq!(
+            *torus.material(),
+            MaterialBuilder::new()
+                .flat_color(Color::new(0.9, 0.6, 0.3))
+                .build()
+        );
+
+        // A ray along the x axis goes through both sides of the tube.
+        let ray = Ray::new(Point::new(-5.0, 1.0, 0.0), POSITIVE_X);
+        let times = torus.times_of_intersections_with(&ray);
+        assert_eq!(times.len(), 4);
+        assert!(close_enough(&times[0], &2.6));
+        assert!(close_enough(&times[3], &7.4));
+    }
+
+    #[test]
+    fn a_material_can_have_its_pattern_in_world_space() {
+        let (world, _camera) =
+            parse_scene_from_yaml("tests/scenes/sphere_with_world_space_stripes.yaml").unwrap();
+
+        let sphere = world.shapes().first().unwrap();
+        assert_eq!(*sphere.material().pattern_space(), PatternSpace::World);
+    }
+
+    #[test]
+    fn a_scene_can_have_several_lights() {
+        let (world, _camera) =
+            parse_scene_from_yaml("tests/scenes/scene_with_three_lights.yaml").unwrap();
+
+        let lights = world.lights();
+        assert_eq!(lights.len(), 3);
+
+        let expected_lights = [
+            PointLight::new(Color::new(0.8, 0.8, 0.8), Point::new(-10.0, 10.0, -10.0)),
+            PointLight::new(Color::new(0.3, 0.3, 0.3), Point::new(10.0, 5.0, -10.0)),
+            PointLight::new(Color::new(0.5, 0.5, 0.6), Point::new(0.0, 10.0, 10.0)),
+        ];
+        for (light, expected_light) in lights.iter().zip(expected_lights.iter()) {
+            assert_eq!(&**light, expected_light as &dyn Light);
+        }
+    }
+
+    #[test]
+    fn an_area_light_is_correctly_parsed() {
+        let (world, _camera) =
+            parse_scene_from_yaml("tests/scenes/scene_with_area_light.yaml").unwrap();
+
+        let mut expected_light = AreaLight::new(
+            Point::new(-1.0, 4.0, -3.0),
+            Vector::new(2.0, 0.0, 0.0),
+            4,
+            Vector::new(0.0, 0.0, 2.0),
+            4,
+            Color::new(1.5, 1.5, 1.5),
+        );
+        expected_light.set_jitter(true);
+
+        assert_eq!(world.lights().len(), 1);
+        assert_eq!(&*world.lights()[0], &expected_light as &dyn Light);
+    }
+
+    #[test]
+    fn a_spotlight_is_correctly_parsed() {
+        let (world, _camera) =
+            parse_scene_from_yaml("tests/scenes/scene_with_spotlight.yaml").unwrap();
+
+        let expected_light = SpotLight::new(
+            Color::new(1.0, 1.0, 0.9),
+            Point::new(0.0, 6.0, -1.0),
+            Vector::new(0.0, -1.0, 0.2),
+            0.3,
+            0.5,
+        );
+
+        assert_eq!(world.lights().len(), 1);
+        assert_eq!(&*world.lights()[0], &expected_light as &dyn Light);
+    }
+
+    #[test]
+    fn a_directional_light_is_correctly_parsed() {
+        let (world, _camera) =
+            parse_scene_from_yaml("tests/scenes/scene_with_directional_light.yaml").unwrap();
+
+        let expected_light =
+            DirectionalLight::new(Color::new(1.0, 0.95, 0.8), Vector::new(1.0, -1.0, 0.5));
+
+        assert_eq!(world.lights().len(), 1);
+        assert_eq!(&*world.lights()[0], &expected_light as &dyn Light);
+    }
+
+    #[test]
+    fn lights_can_have_attenuation() {
+        let (world, _camera) =
+            parse_scene_from_yaml("tests/scenes/scene_with_attenuated_lights.yaml").unwrap();
+
+        let mut first_light =
+            PointLight::new(Color::new(4.0, 4.0, 3.5), Point::new(-2.0, 3.0, -2.0));
+        first_light.set_attenuation(Attenuation::InverseSquare);
+
+        let mut second_light = PointLight::new(WHITE, Point::new(3.0, 4.0, -3.0));
+        second_light.set_attenuation(Attenuation::Coefficients {
+            constant: 1.0,
+            linear: 0.09,
+            quadratic: 0.032,
+        });
+
+        assert_eq!(world.lights().len(), 2);
+        assert_eq!(&*world.lights()[0], &first_light as &dyn Light);
+        assert_eq!(&*world.lights()[1], &second_light as &dyn Light);
+    }
+
+    #[test]
+    fn materials_can_cast_opaque_shadows() {
+        let (world, _camera) =
+            parse_scene_from_yaml("tests/scenes/scene_with_glass_shadows.yaml").unwrap();
+
+        let shapes = world.shapes();
+        assert!(!shapes[1].material().opaque_shadow());
+        assert!(shapes[2].material().opaque_shadow());
+    }
+
+    #[test]
+    fn shapes_can_be_hidden_from_some_rays() {
+        let (world, _camera) =
+            parse_scene_from_yaml("tests/scenes/scene_with_hidden_shapes.yaml").unwrap();
+
+        let shapes = world.shapes();
+        assert_eq!(*shapes[0].visibility(), Visibility::default());
+        assert_eq!(*shapes[1].visibility(), Visibility::new(false, true, true));
+        assert_eq!(*shapes[2].visibility(), Visibility::new(true, false, true));
+        assert_eq!(*shapes[3].visibility(), Visibility::new(true, false, false));
+    }
+
+    #[test]
+    fn an_image_texture_is_correctly_parsed() {
+        let (world, _camera) =
+            parse_scene_from_yaml("tests/scenes/sphere_with_texture.yaml").unwrap();
+
+        let mut texture =
+            ImageTexture::from_file(Path::new("tests/scenes/textures/tiles.png")).unwrap();
+        texture.set_filter(TextureFilter::Bilinear);
+
+        let mut expected_pattern = TextureMapPattern::new(Box::new(texture), UvMapping::Spherical);
+        expected_pattern.set_transform(Transform::y_rotation(0.5));
+
+        let expected_material = MaterialBuilder::new()
+            .pattern(Box::new(expected_pattern))
+            .specular(0.1)
+            .build();
+
+        let sphere = world.shapes().get(1).unwrap();
+        assert!(*sphere.material() == expected_material);
+    }
+
+    #[test]
+    fn textures_can_use_any_mapping() {
+        let (world, _camera) =
+            parse_scene_from_yaml("tests/scenes/shapes_with_mapped_textures.yaml").unwrap();
+
+        let tiles = ImageTexture::from_file(Path::new("tests/scenes/textures/tiles.png")).unwrap();
+        let corners =
+            ImageTexture::from_file(Path::new("tests/scenes/textures/corners.ppm")).unwrap();
+
+        let mut planar = TextureMapPattern::new(Box::new(tiles.clone()), UvMapping::Planar);
+        planar.set_transform(Transform::scaling(4.0, 4.0, 4.0));
+
+        let cube = TextureMapPattern::new(Box::new(corners), UvMapping::Cube);
+
+        let mut clamped_tiles = tiles;
+        clamped_tiles.set_wrap(TextureWrap::Clamp);
+        let mut cylindrical =
+            TextureMapPattern::new(Box::new(clamped_tiles), UvMapping::Cylindrical);
+        cylindrical.set_transform(Transform::scaling(1.0, 2.0, 1.0));
+
+        let expected_patterns: [Box<dyn Pattern>; 3] =
+            [Box::new(planar), Box::new(cube), Box::new(cylindrical)];
+
+        for (shape, expected_pattern) in world.shapes().iter().zip(expected_patterns) {
+            let expected_material = MaterialBuilder::new().pattern(expected_pattern).build();
+            assert!(*shape.material() == expected_material);
+        }
+    }
+
+    #[test]
+    fn uv_checkers_and_align_checks_get_parsed_correctly() {
+        let (world, _camera) =
+            parse_scene_from_yaml("tests/scenes/shapes_with_uv_checkers.yaml").unwrap();
+
+        let planar = TextureMapPattern::new(
+            Box::new(UvCheckersPattern::new(
+                2.0,
+                2.0,
+                Color::new(0.5, 0.5, 0.5),
+                Color::new(0.9, 0.9, 0.9),
+            )),
+            UvMapping::Planar,
+        );
+
+        let spherical = TextureMapPattern::new(
+            Box::new(UvCheckersPattern::new(
+                16.0,
+                8.0,
+                Color::new(0.0, 0.5, 0.0),
+                WHITE,
+            )),
+            UvMapping::Spherical,
+        );
+
+        let align_check = TextureMapPattern::new(
+            Box::new(AlignCheckPattern::new(
+                WHITE,
+                Color::new(1.0, 0.0, 0.0),
+                Color::new(1.0, 1.0, 0.0),
+                Color::new(0.0, 1.0, 0.0),
+                Color::new(0.0, 1.0, 1.0),
+            )),
+            UvMapping::Cube,
+        );
+
+        let expected_patterns: [Box<dyn Pattern>; 3] =
+            [Box::new(planar), Box::new(spherical), Box::new(align_check)];
+
+        for (shape, expected_pattern) in world.shapes().iter().zip(expected_patterns) {
+            let expected_material = MaterialBuilder::new().pattern(expected_pattern).build();
+            assert!(*shape.material() == expected_material);
+        }
+    }
+
+    #[test]
+    fn perturbed_patterns_get_parsed_correctly() {
+        let (world, _camera) =
+            parse_scene_from_yaml("tests/scenes/sphere_with_perturbed_stripes.yaml").unwrap();
+
+        let mut floor_pattern = PerturbedPattern::new(
+            Box::new(Checker3DPattern::new(
+                Color::new(0.3, 0.3, 0.3),
+                Color::new(0.8, 0.8, 0.8),
+            )),
+            0.4,
+            3,
+        );
+        floor_pattern.set_seed(5);
+
+        let mut stripes = StripePattern::new(WHITE, Color::new(0.8, 0.1, 0.1));
+        stripes.set_transform(Transform::scaling(0.2, 0.2, 0.2));
+        let mut sphere_pattern = PerturbedPattern::new(Box::new(stripes), 0.2, 2);
+        sphere_pattern.set_transform(Transform::z_rotation(0.5));
+
+        let expected_patterns: [Box<dyn Pattern>; 2] =
+            [Box::new(floor_pattern), Box::new(sphere_pattern)];
+
+        for (shape, expected_pattern) in world.shapes().iter().zip(expected_patterns) {
+            let expected_material = MaterialBuilder::new().pattern(expected_pattern).build();
+            assert!(*shape.material() == expected_material);
+        }
+    }
+
+    #[test]
+    fn noise_patterns_get_parsed_correctly() {
+        let (world, _camera) = parse_scene_from_yaml("tests/scenes/noise_patterns.yaml").unwrap();
+
+        let mut wood = NoisePattern::new(
+            ColorRamp::new(vec![
+                Color::new(0.55, 0.35, 0.15),
+                Color::new(0.4, 0.22, 0.08),
+                Color::new(0.55, 0.35, 0.15),
+            ]),
+            NoiseMapping::Wood { turbulence: 0.15 },
+            3,
+        );
+        wood.set_seed(8);
+        wood.set_transform(Transform::scaling(0.4, 0.4, 0.4));
+
+        let mut marble = NoisePattern::new(
+            ColorRamp::new(vec![Color::new(0.9, 0.9, 0.9), Color::new(0.3, 0.3, 0.35)]),
+            NoiseMapping::Marble { turbulence: 4.0 },
+            4,
+        );
+        marble.set_transform(Transform::scaling(0.3, 0.3, 0.3));
+
+        let mut clouds = NoisePattern::new(
+            ColorRamp::new(vec![Color::new(0.3, 0.5, 0.9), WHITE]),
+            NoiseMapping::Clouds,
+            6,
+        );
+        clouds.set_seed(3);
+        clouds.set_transform(Transform::scaling(0.5, 0.5, 0.5));
+
+        let checkers = Checker3DPattern::new_with_patterns(
+            Box::new(NoisePattern::new(
+                ColorRamp::new(vec![Color::new(0.1, 0.1, 0.1), Color::new(0.5, 0.5, 0.5)]),
+                NoiseMapping::Marble { turbulence: 5.0 },
+                4,
+            )),
+            Box::new(NoisePattern::new(
+                ColorRamp::new(vec![Color::new(0.8, 0.6, 0.3), Color::new(0.5, 0.3, 0.1)]),
+                NoiseMapping::Wood { turbulence: 0.1 },
+                2,
+            )),
+        );
+
+        let expected_patterns: [Box<dyn Pattern>; 4] = [
+            Box::new(wood),
+            Box::new(marble),
+            Box::new(clouds),
+            Box::new(checkers),
+        ];
+
+        for (shape, expected_pattern) in world.shapes().iter().zip(expected_patterns) {
+            let expected_material = MaterialBuilder::new().pattern(expected_pattern).build();
+            assert!(*shape.material() == expected_material);
+        }
+    }
+
+    #[test]
+    fn worley_patterns_get_parsed_correctly() {
+        let (world, _camera) = parse_scene_from_yaml("tests/scenes/worley_patterns.yaml").unwrap();
+
+        let mut cracks = WorleyPattern::new(
+            WorleyDistance::F2MinusF1,
+            ColorRamp::new(vec![
+                Color::new(0.15, 0.12, 0.1),
+                Color::new(0.6, 0.55, 0.5),
+                Color::new(0.6, 0.55, 0.5),
+            ]),
+        );
+        cracks.set_transform(Transform::scaling(0.7, 0.7, 0.7));
+
+        let mut scales = WorleyPattern::new_with_palette(vec![
+            Color::new(0.2, 0.5, 0.3),
+            Color::new(0.3, 0.6, 0.3),
+            Color::new(0.15, 0.4, 0.25),
+        ]);
+        scales.set_seed(12);
+        scales.set_transform(Transform::scaling(0.25, 0.25, 0.25));
+
+        let mut cells = WorleyPattern::new(
+            WorleyDistance::F1,
+            ColorRamp::new(vec![Color::new(1.0, 0.9, 0.6), Color::new(0.6, 0.2, 0.1)]),
+        );
+        cells.set_seed(4);
+        cells.set_transform(Transform::scaling(0.3, 0.3, 0.3));
+
+        let expected_patterns: [Box<dyn Pattern>; 3] =
+            [Box::new(cracks), Box::new(scales), Box::new(cells)];
+
+        for (shape, expected_pattern) in world.shapes().iter().zip(expected_patterns) {
+            let expected_material = MaterialBuilder::new().pattern(expected_pattern).build();
+            assert!(*shape.material() == expected_material);
+        }
+    }
+
+    #[test]
+    fn color_ramps_get_parsed_correctly() {
+        let (world, _camera) =
+            parse_scene_from_yaml("tests/scenes/patterns_with_color_ramps.yaml").unwrap();
+
+        let mut ring_ramp = ColorRamp::from_stops(vec![
+            (0.0, Color::new(0.9, 0.9, 0.9)),
+            (0.3, Color::new(0.2, 0.4, 0.8)),
+            (0.6, Color::new(0.9, 0.6, 0.2)),
+        ]);
+        ring_ramp.set_interpolation(RampInterpolation::Constant);
+        let rings = RingPattern::new_with_ramp(ring_ramp);
+
+        let mut gradient_ramp = ColorRamp::from_stops(vec![
+            (0.0, Color::new(1.0, 0.0, 0.0)),
+            (0.5, Color::new(1.0, 1.0, 0.0)),
+            (1.0, Color::new(0.0, 0.0, 1.0)),
+        ]);
+        gradient_ramp.set_interpolation(RampInterpolation::Smoothstep);
+        let mut gradient = GradientPattern::new_with_ramp(gradient_ramp);
+        gradient.set_transform(Transform::scaling(2.0, 2.0, 2.0));
+
+        let mut marble = NoisePattern::new(
+            ColorRamp::from_stops(vec![
+                (0.0, Color::new(0.1, 0.1, 0.1)),
+                (0.8, Color::new(0.1, 0.1, 0.1)),
+                (1.0, Color::new(0.9, 0.9, 0.9)),
+            ]),
+            NoiseMapping::Marble { turbulence: 5.0 },
+            4,
+        );
+        marble.set_transform(Transform::scaling(0.3, 0.3, 0.3));
+
+        let expected_patterns: [Box<dyn Pattern>; 3] =
+            [Box::new(rings), Box::new(gradient), Box::new(marble)];
+
+        for (shape, expected_pattern) in world.shapes().iter().zip(expected_patterns) {
+            let expected_material = MaterialBuilder::new().pattern(expected_pattern).build();
+            assert!(*shape.material() == expected_material);
+        }
+    }
+
+    #[test]
+    fn radial_spherical_and_angular_gradients_get_parsed_correctly() {
+        let (world, _camera) = parse_scene_from_yaml("tests/scenes/shaped_gradients.yaml").unwrap();
+
+        let mut glow_ramp = ColorRamp::from_stops(vec![
+            (0.0, Color::new(1.0, 0.9, 0.5)),
+            (0.3, Color::new(0.6, 0.3, 0.2)),
+            (1.0, Color::new(0.1, 0.1, 0.2)),
+        ]);
+        glow_ramp.set_interpolation(RampInterpolation::Smoothstep);
+        let mut radial = RadialGradientPattern::new(glow_ramp);
+        radial.set_repeat(GradientRepeat::Mirror);
+        radial.set_transform(Transform::scaling(3.0, 3.0, 3.0));
+
+        let mut spherical = SphericalGradientPattern::new(ColorRamp::new(vec![
+            Color::new(1.0, 1.0, 0.8),
+            Color::new(1.0, 0.5, 0.0),
+            Color::new(0.3, 0.0, 0.0),
+        ]));
+        spherical.set_transform(Transform::scaling(1.5, 1.5, 1.5));
+
+        let mut wheel = AngularGradientPattern::new(ColorRamp::new(vec![
+            Color::new(1.0, 0.0, 0.0),
+            Color::new(1.0, 1.0, 0.0),
+            Color::new(0.0, 1.0, 0.0),
+            Color::new(0.0, 1.0, 1.0),
+            Color::new(0.0, 0.0, 1.0),
+            Color::new(1.0, 0.0, 1.0),
+            Color::new(1.0, 0.0, 0.0),
+        ]));
+        wheel.set_transform(Transform::x_rotation(1.5));
+
+        let mut sunburst = AngularGradientPattern::new(ColorRamp::new(vec![
+            Color::new(1.0, 0.8, 0.2),
+            Color::new(0.9, 0.3, 0.1),
+        ]));
+        sunburst.set_sectors(12);
+        sunburst.set_repeat(GradientRepeat::Mirror);
+
+        let expected_patterns: [Box<dyn Pattern>; 4] = [
+            Box::new(radial),
+            Box::new(spherical),
+            Box::new(wheel),
+            Box::new(sunburst),
+        ];
+
+        for (shape, expected_pattern) in world.shapes().iter().zip(expected_patterns) {
+            let expected_material = MaterialBuilder::new().pattern(expected_pattern).build();
+            assert!(*shape.material() == expected_material);
+        }
+    }
+
     #[test]
     fn a_scene_with_three_spheres_gets_parsed_correctly() {
         let (world, _camera) = parse_scene_from_yaml("tests/scenes/three_spheres.yaml").unwrap();
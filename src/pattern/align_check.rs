@@ -0,0 +1,102 @@
+use crate::prelude::*;
+
+/// A 2D pattern with a different color in each of its corners, to see which way (u, v)
+/// coordinates end up on a shape. Handy for checking the orientation of each face of a cube map.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlignCheckPattern {
+    main: Color,
+    upper_left: Color,
+    upper_right: Color,
+    bottom_left: Color,
+    bottom_right: Color,
+}
+
+impl AlignCheckPattern {
+    pub fn new(
+        main: Color,
+        upper_left: Color,
+        upper_right: Color,
+        bottom_left: Color,
+        bottom_right: Color,
+    ) -> Self {
+        AlignCheckPattern {
+            main,
+            upper_left,
+            upper_right,
+            bottom_left,
+            bottom_right,
+        }
+    }
+}
+
+impl UvPattern for AlignCheckPattern {
+    fn color_at_uv(&self, u: f64, v: f64) -> Color {
+        if v > 0.8 {
+            if u < 0.2 {
+                return self.upper_left;
+            }
+            if u > 0.8 {
+                return self.upper_right;
+            }
+        } else if v < 0.2 {
+            if u < 0.2 {
+                return self.bottom_left;
+            }
+            if u > 0.8 {
+                return self.bottom_right;
+            }
+        }
+
+        self.main
+    }
+}
+
+impl Eq for AlignCheckPattern {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn each_corner_has_its_own_color() {
+        let main = Color::new(1.0, 1.0, 1.0);
+        let upper_left = Color::new(1.0, 0.0, 0.0);
+        let upper_right = Color::new(1.0, 1.0, 0.0);
+        let bottom_left = Color::new(0.0, 1.0, 0.0);
+        let bottom_right = Color::new(0.0, 1.0, 1.0);
+        let pattern =
+            AlignCheckPattern::new(main, upper_left, upper_right, bottom_left, bottom_right);
+
+        assert_eq!(pattern.color_at_uv(0.5, 0.5), main);
+        assert_eq!(pattern.color_at_uv(0.1, 0.9), upper_left);
+        assert_eq!(pattern.color_at_uv(0.9, 0.9), upper_right);
+        assert_eq!(pattern.color_at_uv(0.1, 0.1), bottom_left);
+        assert_eq!(pattern.color_at_uv(0.9, 0.1), bottom_right);
+        assert_eq!(pattern.color_at_uv(0.5, 0.9), main);
+        assert_eq!(pattern.color_at_uv(0.1, 0.5), main);
+    }
+
+    #[test]
+    fn the_corners_show_up_on_every_face_of_a_cube() {
+        let upper_left = Color::new(1.0, 0.0, 0.0);
+        let pattern = TextureMapPattern::new(
+            Box::new(AlignCheckPattern::new(
+                WHITE, upper_left, BLACK, BLACK, BLACK,
+            )),
+            UvMapping::Cube,
+        );
+
+        let upper_left_of_each_face = [
+            Point::new(1.0, 0.9, 0.9),
+            Point::new(-1.0, 0.9, -0.9),
+            Point::new(-0.9, 1.0, -0.9),
+            Point::new(-0.9, -1.0, 0.9),
+            Point::new(-0.9, 0.9, 1.0),
+            Point::new(0.9, 0.9, -1.0),
+        ];
+
+        for point in upper_left_of_each_face {
+            assert_eq!(pattern.color_at(&point), upper_left, "{:?}", point);
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use std::f64::consts::PI;
+
+use crate::prelude::*;
+
+/// Changes color going around the y axis, starting from the x axis and turning towards the z
+/// axis. Good for color wheels and, split into sectors, sunbursts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AngularGradientPattern {
+    ramp: ColorRamp,
+    sectors: usize,
+    repeat: GradientRepeat,
+    transform: Transform,
+}
+
+impl AngularGradientPattern {
+    /// Goes through the ramp once all the way around.
+    pub fn new(ramp: ColorRamp) -> Self {
+        AngularGradientPattern {
+            ramp,
+            sectors: 1,
+            repeat: GradientRepeat::Repeat,
+            transform: Transform::new(IDENTITY_MATRIX),
+        }
+    }
+
+    /// Goes through the ramp once for each of a number of equal slices of the full turn.
+    pub fn set_sectors(&mut self, sectors: usize) {
+        self.sectors = sectors.max(1);
+    }
+
+    pub fn set_repeat(&mut self, repeat: GradientRepeat) {
+        self.repeat = repeat;
+    }
+}
+
+impl Pattern for AngularGradientPattern {
+    fn color_at(&self, point: &Point) -> Color {
+        let pattern_space_point = self.transform.invert().unwrap() * *point;
+
+        let turn =
+            (pattern_space_point.z().atan2(*pattern_space_point.x()) / (2.0 * PI)).rem_euclid(1.0);
+
+        self.ramp
+            .color_at(self.repeat.apply(turn * self.sectors as f64))
+    }
+}
+
+impl Eq for AngularGradientPattern {}
+
+impl Transformable for AngularGradientPattern {
+    fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn pattern() -> AngularGradientPattern {
+        AngularGradientPattern::new(ColorRamp::new(vec![WHITE, BLACK]))
+    }
+
+    #[test]
+    fn the_color_changes_going_around_the_y_axis() {
+        let pattern = pattern();
+
+        assert_eq!(pattern.color_at(&Point::new(1.0, 0.0, 0.0)), WHITE);
+        assert_eq!(
+            pattern.color_at(&Point::new(0.0, 3.0, 1.0)),
+            Color::new(0.75, 0.75, 0.75)
+        );
+        assert_eq!(
+            pattern.color_at(&Point::new(-2.0, 0.0, 0.0)),
+            Color::new(0.5, 0.5, 0.5)
+        );
+        assert_eq!(
+            pattern.color_at(&Point::new(0.0, -1.0, -0.5)),
+            Color::new(0.25, 0.25, 0.25)
+        );
+    }
+
+    #[test]
+    fn the_color_does_not_change_with_the_distance_from_the_axis() {
+        let pattern = pattern();
+
+        assert_eq!(
+            pattern.color_at(&Point::new(0.0, 0.0, 0.1)),
+            pattern.color_at(&Point::new(0.0, 0.0, 10.0))
+        );
+    }
+
+    #[test]
+    fn the_ramp_can_repeat_in_sectors() {
+        let mut pattern = pattern();
+        pattern.set_sectors(4);
+
+        assert_eq!(pattern.color_at(&Point::new(1.0, 0.0, 0.0)), WHITE);
+        assert_eq!(pattern.color_at(&Point::new(0.0, 0.0, 1.0)), WHITE);
+        assert_eq!(
+            pattern.color_at(&Point::new(-1.0, 0.0, -1.0)),
+            Color::new(0.5, 0.5, 0.5)
+        );
+
+        pattern.set_repeat(GradientRepeat::Mirror);
+
+        assert_eq!(pattern.color_at(&Point::new(0.0, 0.0, 1.0)), BLACK);
+    }
+}
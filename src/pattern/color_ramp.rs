@@ -0,0 +1,164 @@
+use crate::prelude::*;
+
+/// How the colors of a ramp get blended in between its stops.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RampInterpolation {
+    /// Evenly, from one color to the next.
+    Linear,
+    /// Easing out of one color and into the next, so there are no sharp corners at the stops.
+    Smoothstep,
+    /// Not at all: each color is kept until the next stop, which gives hard bands.
+    Constant,
+}
+
+/// Colors placed at stops along a line, usually from 0 to 1, and blended into each other in
+/// between.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorRamp {
+    // Ordered by position.
+    stops: Vec<(f64, Color)>,
+    interpolation: RampInterpolation,
+}
+
+impl ColorRamp {
+    /// The colors get spread out evenly from 0 to 1.
+    pub fn new(colors: Vec<Color>) -> Self {
+        assert!(!colors.is_empty(), "A color ramp needs at least one color");
+
+        let segments = (colors.len() - 1).max(1) as f64;
+        let stops = colors
+            .into_iter()
+            .enumerate()
+            .map(|(index, color)| (index as f64 / segments, color))
+            .collect();
+
+        ColorRamp {
+            stops,
+            interpolation: RampInterpolation::Linear,
+        }
+    }
+
+    /// Each color is placed at the position it comes with, in whatever order they're given.
+    pub fn from_stops(mut stops: Vec<(f64, Color)>) -> Self {
+        assert!(!stops.is_empty(), "A color ramp needs at least one stop");
+
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        ColorRamp {
+            stops,
+            interpolation: RampInterpolation::Linear,
+        }
+    }
+
+    pub fn set_interpolation(&mut self, interpolation: RampInterpolation) {
+        self.interpolation = interpolation;
+    }
+
+    /// Values before the first stop or after the last one get the color of that stop.
+    pub fn color_at(&self, value: f64) -> Color {
+        let (first_position, first_color) = self.stops[0];
+        let (last_position, last_color) = self.stops[self.stops.len() - 1];
+
+        if value.is_nan() || value <= first_position {
+            return first_color;
+        }
+        if value >= last_position {
+            return last_color;
+        }
+
+        let index = self
+            .stops
+            .windows(2)
+            .position(|pair| value < pair[1].0)
+            .unwrap();
+        let (start_position, start_color) = self.stops[index];
+        let (end_position, end_color) = self.stops[index + 1];
+
+        let amount = (value - start_position) / (end_position - start_position);
+        let amount = match self.interpolation {
+            RampInterpolation::Linear => amount,
+            RampInterpolation::Smoothstep => amount * amount * (3.0 - 2.0 * amount),
+            RampInterpolation::Constant => 0.0,
+        };
+
+        start_color + (end_color - start_color) * amount
+    }
+}
+
+impl Eq for ColorRamp {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn grey(value: f64) -> Color {
+        Color::new(value, value, value)
+    }
+
+    #[test]
+    fn a_ramp_blends_between_its_colors() {
+        let ramp = ColorRamp::new(vec![BLACK, WHITE, BLACK]);
+
+        assert_eq!(ramp.color_at(0.0), BLACK);
+        assert_eq!(ramp.color_at(0.25), grey(0.5));
+        assert_eq!(ramp.color_at(0.5), WHITE);
+        assert_eq!(ramp.color_at(0.75), grey(0.5));
+        assert_eq!(ramp.color_at(1.0), BLACK);
+    }
+
+    #[test]
+    fn values_outside_of_the_ramp_get_the_colors_at_its_ends() {
+        let ramp = ColorRamp::new(vec![BLACK, WHITE]);
+
+        assert_eq!(ramp.color_at(-3.0), BLACK);
+        assert_eq!(ramp.color_at(1.5), WHITE);
+    }
+
+    #[test]
+    fn a_ramp_with_one_color_is_that_color_everywhere() {
+        let ramp = ColorRamp::new(vec![WHITE]);
+
+        assert_eq!(ramp.color_at(0.3), WHITE);
+    }
+
+    #[test]
+    fn stops_can_be_placed_anywhere_and_in_any_order() {
+        let ramp = ColorRamp::from_stops(vec![(0.8, WHITE), (0.2, BLACK), (0.4, grey(0.5))]);
+
+        assert_eq!(ramp.color_at(0.1), BLACK);
+        assert_eq!(ramp.color_at(0.3), grey(0.25));
+        assert_eq!(ramp.color_at(0.4), grey(0.5));
+        assert_eq!(ramp.color_at(0.7), grey(0.875));
+        assert_eq!(ramp.color_at(0.9), WHITE);
+    }
+
+    #[test]
+    fn smoothstep_eases_in_and_out_of_the_stops() {
+        let mut ramp = ColorRamp::new(vec![BLACK, WHITE]);
+        ramp.set_interpolation(RampInterpolation::Smoothstep);
+
+        assert_eq!(ramp.color_at(0.25), grey(0.15625));
+        assert_eq!(ramp.color_at(0.5), grey(0.5));
+        assert_eq!(ramp.color_at(0.75), grey(0.84375));
+    }
+
+    #[test]
+    fn constant_interpolation_keeps_each_color_until_the_next_stop() {
+        let mut ramp = ColorRamp::from_stops(vec![(0.0, BLACK), (0.5, grey(0.5)), (0.9, WHITE)]);
+        ramp.set_interpolation(RampInterpolation::Constant);
+
+        assert_eq!(ramp.color_at(0.49), BLACK);
+        assert_eq!(ramp.color_at(0.5), grey(0.5));
+        assert_eq!(ramp.color_at(0.89), grey(0.5));
+        assert_eq!(ramp.color_at(0.9), WHITE);
+    }
+
+    #[test]
+    fn stops_at_the_same_position_make_a_hard_edge() {
+        let ramp =
+            ColorRamp::from_stops(vec![(0.0, BLACK), (0.5, BLACK), (0.5, WHITE), (1.0, WHITE)]);
+
+        assert_eq!(ramp.color_at(0.4999), BLACK);
+        assert_eq!(ramp.color_at(0.5001), WHITE);
+    }
+}
@@ -1,17 +1,30 @@
 use crate::prelude::*;
 
+#[derive(Debug, Clone, PartialEq)]
+enum GradientColors {
+    // Keeps on changing the same way past both colors.
+    Pair(Color, Color),
+    Ramp(ColorRamp),
+}
+
+/// Changes color along the x axis, from the start of the ramp at x = -0.5 to its end at x = 0.5.
 #[derive(Debug, Clone, PartialEq)]
 pub struct GradientPattern {
-    start: Color,
-    end: Color,
+    colors: GradientColors,
     transform: Transform,
 }
 
 impl GradientPattern {
     pub fn new(start: Color, end: Color) -> Self {
         GradientPattern {
-            start,
-            end,
+            colors: GradientColors::Pair(start, end),
+            transform: Transform::new(IDENTITY_MATRIX),
+        }
+    }
+
+    pub fn new_with_ramp(ramp: ColorRamp) -> Self {
+        GradientPattern {
+            colors: GradientColors::Ramp(ramp),
             transform: Transform::new(IDENTITY_MATRIX),
         }
     }
@@ -23,9 +36,14 @@ impl Pattern for GradientPattern {
         let point_x = point_in_pattern_space.x();
         let distance_from_beginning = point_x + 0.5;
 
-        let color_difference = self.end - self.start;
+        match &self.colors {
+            GradientColors::Pair(start, end) => {
+                let color_difference = *end - *start;
 
-        self.start + (color_difference * distance_from_beginning)
+                *start + (color_difference * distance_from_beginning)
+            }
+            GradientColors::Ramp(ramp) => ramp.color_at(distance_from_beginning),
+        }
     }
 }
 
@@ -110,4 +128,33 @@ mod test {
             Color::new(0.25, 0.25, 0.25)
         );
     }
+
+    #[test]
+    fn a_gradient_can_go_through_the_colors_of_a_ramp() {
+        let ramp = ColorRamp::from_stops(vec![
+            (0.0, WHITE),
+            (0.5, Color::new(1.0, 0.0, 0.0)),
+            (1.0, BLACK),
+        ]);
+        let pattern = GradientPattern::new_with_ramp(ramp);
+
+        assert_eq!(pattern.color_at(&Point::new(-0.5, 0.0, 0.0)), WHITE);
+        assert_eq!(
+            pattern.color_at(&Point::new(-0.25, 0.0, 0.0)),
+            Color::new(1.0, 0.5, 0.5)
+        );
+        assert_eq!(
+            pattern.color_at(&Point::new(0.0, 0.0, 0.0)),
+            Color::new(1.0, 0.0, 0.0)
+        );
+        assert_eq!(pattern.color_at(&Point::new(0.5, 0.0, 0.0)), BLACK);
+    }
+
+    #[test]
+    fn a_gradient_with_a_ramp_stops_changing_past_its_ends() {
+        let pattern = GradientPattern::new_with_ramp(ColorRamp::new(vec![WHITE, BLACK]));
+
+        assert_eq!(pattern.color_at(&Point::new(-3.0, 0.0, 0.0)), WHITE);
+        assert_eq!(pattern.color_at(&Point::new(2.0, 0.0, 0.0)), BLACK);
+    }
 }
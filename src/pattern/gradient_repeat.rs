@@ -0,0 +1,55 @@
+/// What the gradients that spread out from a center do past the end of their ramp.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GradientRepeat {
+    /// The last color of the ramp carries on forever.
+    Clamp,
+    /// The ramp starts over from the beginning.
+    Repeat,
+    /// The ramp goes back and forth, from start to end and then end to start.
+    Mirror,
+}
+
+impl GradientRepeat {
+    /// Where on the ramp, from 0 to 1, a value that might go past it ends up.
+    pub fn apply(&self, value: f64) -> f64 {
+        match self {
+            GradientRepeat::Clamp => value.clamp(0.0, 1.0),
+            GradientRepeat::Repeat => value.rem_euclid(1.0),
+            GradientRepeat::Mirror => {
+                let value = value.rem_euclid(2.0);
+                if value > 1.0 {
+                    2.0 - value
+                } else {
+                    value
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn clamping_stops_at_the_ends() {
+        assert_eq!(GradientRepeat::Clamp.apply(-0.5), 0.0);
+        assert_eq!(GradientRepeat::Clamp.apply(0.25), 0.25);
+        assert_eq!(GradientRepeat::Clamp.apply(3.5), 1.0);
+    }
+
+    #[test]
+    fn repeating_starts_over() {
+        assert_eq!(GradientRepeat::Repeat.apply(0.25), 0.25);
+        assert_eq!(GradientRepeat::Repeat.apply(1.25), 0.25);
+        assert_eq!(GradientRepeat::Repeat.apply(-0.25), 0.75);
+    }
+
+    #[test]
+    fn mirroring_goes_back_and_forth() {
+        assert_eq!(GradientRepeat::Mirror.apply(0.25), 0.25);
+        assert_eq!(GradientRepeat::Mirror.apply(1.25), 0.75);
+        assert_eq!(GradientRepeat::Mirror.apply(2.25), 0.25);
+        assert_eq!(GradientRepeat::Mirror.apply(-0.25), 0.25);
+    }
+}
@@ -0,0 +1,214 @@
+use std::{error::Error, path::Path};
+
+use crate::prelude::*;
+
+/// How a color is picked out of the pixels of an image.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TextureFilter {
+    /// Just the color of the pixel the coordinates land in, which looks blocky up close.
+    Nearest,
+    /// A blend of the four pixels nearest the coordinates, which smooths over the edges of them.
+    Bilinear,
+}
+
+/// What happens to (u, v) coordinates outside of 0 to 1.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TextureWrap {
+    /// The image is tiled over and over again.
+    Repeat,
+    /// The pixels at the edges of the image are stretched out forever.
+    Clamp,
+}
+
+/// A 2D pattern made from the pixels of an image. The bottom left of the image is at (0, 0), and
+/// the top right is at (1, 1).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageTexture {
+    width: usize,
+    height: usize,
+    // Row by row, starting from the top of the image.
+    pixels: Vec<Color>,
+    filter: TextureFilter,
+    wrap: TextureWrap,
+}
+
+impl ImageTexture {
+    pub fn new(width: usize, height: usize, pixels: Vec<Color>) -> Self {
+        assert_eq!(
+            pixels.len(),
+            width * height,
+            "An image texture needs a color for every one of its pixels"
+        );
+
+        ImageTexture {
+            width,
+            height,
+            pixels,
+            filter: TextureFilter::Nearest,
+            wrap: TextureWrap::Repeat,
+        }
+    }
+
+    /// Loads the image from a file. Anything the `image` crate is set up to read will work, which
+    /// is PNG and PPM files.
+    pub fn from_file(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let image = image::open(path)?.to_rgb8();
+
+        let pixels = image
+            .pixels()
+            .map(|pixel| {
+                Color::new(
+                    pixel[0] as f64 / 255.0,
+                    pixel[1] as f64 / 255.0,
+                    pixel[2] as f64 / 255.0,
+                )
+            })
+            .collect();
+
+        Ok(ImageTexture::new(
+            image.width() as usize,
+            image.height() as usize,
+            pixels,
+        ))
+    }
+
+    pub fn set_filter(&mut self, filter: TextureFilter) {
+        self.filter = filter;
+    }
+
+    pub fn set_wrap(&mut self, wrap: TextureWrap) {
+        self.wrap = wrap;
+    }
+
+    // Gets the pixel in the given column and row (counting rows down from the top), wrapping
+    // around if they're off the edge of the image.
+    fn pixel(&self, column: i64, row: i64) -> Color {
+        let column = wrap_index(column, self.width, self.wrap);
+        let row = wrap_index(row, self.height, self.wrap);
+
+        self.pixels[row * self.width + column]
+    }
+}
+
+fn wrap_index(index: i64, size: usize, wrap: TextureWrap) -> usize {
+    match wrap {
+        TextureWrap::Repeat => index.rem_euclid(size as i64) as usize,
+        TextureWrap::Clamp => index.clamp(0, size as i64 - 1) as usize,
+    }
+}
+
+impl UvPattern for ImageTexture {
+    fn color_at_uv(&self, u: f64, v: f64) -> Color {
+        // v goes up the image, but the rows go down it.
+        let x = u * self.width as f64;
+        let y = (1.0 - v) * self.height as f64;
+
+        match self.filter {
+            TextureFilter::Nearest => self.pixel(x.floor() as i64, y.floor() as i64),
+            TextureFilter::Bilinear => {
+                // The color of each pixel is at its middle, so we blend between the middles.
+                let x = x - 0.5;
+                let y = y - 0.5;
+
+                let column = x.floor();
+                let row = y.floor();
+                let x_amount = x - column;
+                let y_amount = y - row;
+
+                let (column, row) = (column as i64, row as i64);
+
+                let top = self.pixel(column, row) * (1.0 - x_amount)
+                    + self.pixel(column + 1, row) * x_amount;
+                let bottom = self.pixel(column, row + 1) * (1.0 - x_amount)
+                    + self.pixel(column + 1, row + 1) * x_amount;
+
+                top * (1.0 - y_amount) + bottom * y_amount
+            }
+        }
+    }
+}
+
+impl Eq for ImageTexture {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Red, green, blue and white in the top left, top right, bottom left and bottom right. These
+    // are given in the same order as the channels of the image files.
+    fn corner_colors() -> [Color; 4] {
+        [
+            Color::new(1.0, 0.0, 0.0),
+            Color::new(0.0, 1.0, 0.0),
+            Color::new(0.0, 0.0, 1.0),
+            WHITE,
+        ]
+    }
+
+    fn corners() -> ImageTexture {
+        ImageTexture::new(2, 2, corner_colors().to_vec())
+    }
+
+    #[test]
+    fn the_nearest_pixel_is_used_by_default() {
+        let texture = corners();
+        let [red, green, blue, white] = corner_colors();
+
+        assert_eq!(texture.color_at_uv(0.25, 0.75), red);
+        assert_eq!(texture.color_at_uv(0.75, 0.75), green);
+        assert_eq!(texture.color_at_uv(0.25, 0.25), blue);
+        assert_eq!(texture.color_at_uv(0.75, 0.25), white);
+        assert_eq!(texture.color_at_uv(0.01, 0.01), blue);
+    }
+
+    #[test]
+    fn bilinear_filtering_blends_the_nearest_pixels() {
+        let mut texture = corners();
+        let [red, green, blue, white] = corner_colors();
+        texture.set_filter(TextureFilter::Bilinear);
+        texture.set_wrap(TextureWrap::Clamp);
+
+        // Right in the middle of a pixel there's nothing to blend.
+        assert_eq!(texture.color_at_uv(0.25, 0.75), red);
+
+        // Halfway between the middles of the top two pixels.
+        assert_eq!(texture.color_at_uv(0.5, 0.75), (red + green) * 0.5);
+
+        // Right in the middle of the image, all four are blended.
+        assert_eq!(
+            texture.color_at_uv(0.5, 0.5),
+            (red + green + blue + white) * 0.25
+        );
+    }
+
+    #[test]
+    fn repeating_textures_tile_forever() {
+        let texture = corners();
+        let [red, green, ..] = corner_colors();
+
+        assert_eq!(texture.color_at_uv(1.25, 0.75), red);
+        assert_eq!(texture.color_at_uv(-0.25, 0.75), green);
+        assert_eq!(texture.color_at_uv(0.25, -0.25), red);
+    }
+
+    #[test]
+    fn clamped_textures_stretch_out_their_edges() {
+        let mut texture = corners();
+        let [red, green, blue, ..] = corner_colors();
+        texture.set_wrap(TextureWrap::Clamp);
+
+        assert_eq!(texture.color_at_uv(1.25, 0.75), green);
+        assert_eq!(texture.color_at_uv(-0.25, 0.75), red);
+        assert_eq!(texture.color_at_uv(0.25, -3.0), blue);
+    }
+
+    #[test]
+    fn png_and_ppm_files_can_be_loaded() {
+        for file in ["corners.png", "corners.ppm"] {
+            let path = Path::new("tests/scenes/textures").join(file);
+            let texture = ImageTexture::from_file(&path).unwrap();
+
+            assert_eq!(texture, corners(), "{}", file);
+        }
+    }
+}
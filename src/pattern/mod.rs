@@ -1,17 +1,49 @@
+mod align_check;
+mod angular_gradient;
 mod blended;
 mod checkers;
+mod color_ramp;
 mod flat;
 mod gradient;
+mod gradient_repeat;
+mod image_texture;
+mod noise;
+mod noise_mapping;
+mod noise_pattern;
 mod pattern;
+mod perturbed;
+mod radial_gradient;
 mod rings;
+mod spherical_gradient;
 mod stripes;
 mod test;
+mod texture_map;
+mod uv_checkers;
+mod uv_mapping;
+mod uv_pattern;
+mod worley;
 
+pub use align_check::AlignCheckPattern;
+pub use angular_gradient::AngularGradientPattern;
 pub use blended::BlendedPattern;
 pub use checkers::Checker3DPattern;
+pub use color_ramp::{ColorRamp, RampInterpolation};
 pub use flat::FlatPattern;
 pub use gradient::GradientPattern;
+pub use gradient_repeat::GradientRepeat;
+pub use image_texture::{ImageTexture, TextureFilter, TextureWrap};
+pub use noise::Perlin;
+pub use noise_mapping::NoiseMapping;
+pub use noise_pattern::NoisePattern;
 pub use pattern::Pattern;
+pub use perturbed::PerturbedPattern;
+pub use radial_gradient::RadialGradientPattern;
 pub use rings::RingPattern;
+pub use spherical_gradient::SphericalGradientPattern;
 pub use stripes::StripePattern;
 pub use test::TestPattern;
+pub use texture_map::TextureMapPattern;
+pub use uv_checkers::UvCheckersPattern;
+pub use uv_mapping::UvMapping;
+pub use uv_pattern::UvPattern;
+pub use worley::{WorleyDistance, WorleyPattern};
@@ -0,0 +1,200 @@
+use crate::prelude::*;
+
+/// Ken Perlin's improved gradient noise. It changes smoothly from point to point, goes from
+/// roughly -1 to 1, and is 0 on every point with whole number coordinates. The same seed always
+/// gives the same noise.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Perlin {
+    // A shuffle of 0 to 255, written out twice so lookups never have to wrap around.
+    permutation: Vec<u8>,
+}
+
+impl Perlin {
+    pub fn new(seed: u64) -> Self {
+        let mut table: Vec<u8> = (0..=255).collect();
+
+        // Fisher-Yates, with a hash of the seed standing in for a random number generator.
+        for i in (1..table.len()).rev() {
+            let j = (hash_values(&[seed, i as u64]) % (i as u64 + 1)) as usize;
+            table.swap(i, j);
+        }
+
+        let mut permutation = table.clone();
+        permutation.extend(table);
+
+        Perlin { permutation }
+    }
+
+    pub fn noise(&self, point: &Point) -> f64 {
+        let (x, y, z) = (point.x(), point.y(), point.z());
+
+        let (cell_x, cell_y, cell_z) = (x.floor(), y.floor(), z.floor());
+        let (x, y, z) = (x - cell_x, y - cell_y, z - cell_z);
+        let xi = cell_x.rem_euclid(256.0) as usize;
+        let yi = cell_y.rem_euclid(256.0) as usize;
+        let zi = cell_z.rem_euclid(256.0) as usize;
+
+        let (u, v, w) = (fade(x), fade(y), fade(z));
+
+        let p = &self.permutation;
+        let a = p[xi] as usize + yi;
+        let aa = p[a] as usize + zi;
+        let ab = p[a + 1] as usize + zi;
+        let b = p[xi + 1] as usize + yi;
+        let ba = p[b] as usize + zi;
+        let bb = p[b + 1] as usize + zi;
+
+        lerp(
+            w,
+            lerp(
+                v,
+                lerp(u, grad(p[aa], x, y, z), grad(p[ba], x - 1.0, y, z)),
+                lerp(
+                    u,
+                    grad(p[ab], x, y - 1.0, z),
+                    grad(p[bb], x - 1.0, y - 1.0, z),
+                ),
+            ),
+            lerp(
+                v,
+                lerp(
+                    u,
+                    grad(p[aa + 1], x, y, z - 1.0),
+                    grad(p[ba + 1], x - 1.0, y, z - 1.0),
+                ),
+                lerp(
+                    u,
+                    grad(p[ab + 1], x, y - 1.0, z - 1.0),
+                    grad(p[bb + 1], x - 1.0, y - 1.0, z - 1.0),
+                ),
+            ),
+        )
+    }
+
+    /// Fractal noise: `octaves` layers of noise, each with twice the detail and half the strength
+    /// of the one before, scaled back down to roughly -1 to 1.
+    pub fn fractal(&self, point: &Point, octaves: usize) -> f64 {
+        let mut total = 0.0;
+        let mut frequency = 1.0;
+        let mut amplitude = 1.0;
+        let mut max_amplitude = 0.0;
+
+        for _ in 0..octaves.max(1) {
+            total += self.noise(&(*point * frequency)) * amplitude;
+            max_amplitude += amplitude;
+            frequency *= 2.0;
+            amplitude /= 2.0;
+        }
+
+        total / max_amplitude
+    }
+
+    /// Like `fractal`, but adding up how far each layer is from 0, which gives creases where the
+    /// noise crosses 0. It goes from 0 to roughly 1.
+    pub fn turbulence(&self, point: &Point, octaves: usize) -> f64 {
+        let mut total = 0.0;
+        let mut frequency = 1.0;
+        let mut amplitude = 1.0;
+        let mut max_amplitude = 0.0;
+
+        for _ in 0..octaves.max(1) {
+            total += self.noise(&(*point * frequency)).abs() * amplitude;
+            max_amplitude += amplitude;
+            frequency *= 2.0;
+            amplitude /= 2.0;
+        }
+
+        total / max_amplitude
+    }
+}
+
+fn fade(t: f64) -> f64 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+fn lerp(t: f64, a: f64, b: f64) -> f64 {
+    a + t * (b - a)
+}
+
+// The dot product with one of 12 gradients pointing at the edges of a cube, picked by the hash.
+fn grad(hash: u8, x: f64, y: f64, z: f64) -> f64 {
+    let h = hash & 15;
+    let u = if h < 8 { x } else { y };
+    let v = if h < 4 {
+        y
+    } else if h == 12 || h == 14 {
+        x
+    } else {
+        z
+    };
+
+    (if h & 1 == 0 { u } else { -u }) + (if h & 2 == 0 { v } else { -v })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sample_points() -> Vec<Point> {
+        (0..200)
+            .map(|i| {
+                let i = i as f64;
+                Point::new(i * 0.37 - 30.0, i * 0.13 + 2.5, i * -0.71 + 4.2)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn noise_is_zero_on_whole_number_coordinates() {
+        let perlin = Perlin::new(0);
+
+        assert_eq!(perlin.noise(&ORIGIN), 0.0);
+        assert_eq!(perlin.noise(&Point::new(3.0, -7.0, 12.0)), 0.0);
+    }
+
+    #[test]
+    fn noise_stays_within_minus_one_and_one() {
+        let perlin = Perlin::new(7);
+
+        for point in sample_points() {
+            let noise = perlin.noise(&point);
+            assert!((-1.0..=1.0).contains(&noise), "{}", noise);
+
+            let fractal = perlin.fractal(&point, 4);
+            assert!((-1.0..=1.0).contains(&fractal), "{}", fractal);
+
+            let turbulence = perlin.turbulence(&point, 4);
+            assert!((0.0..=1.0).contains(&turbulence), "{}", turbulence);
+        }
+    }
+
+    #[test]
+    fn the_same_seed_always_gives_the_same_noise() {
+        let first = Perlin::new(42);
+        let second = Perlin::new(42);
+
+        assert_eq!(first, second);
+        for point in sample_points() {
+            assert_eq!(first.noise(&point), second.noise(&point));
+        }
+    }
+
+    #[test]
+    fn different_seeds_give_different_noise() {
+        let first = Perlin::new(1);
+        let second = Perlin::new(2);
+
+        assert!(sample_points()
+            .iter()
+            .any(|point| first.noise(point) != second.noise(point)));
+    }
+
+    #[test]
+    fn noise_changes_smoothly() {
+        let perlin = Perlin::new(3);
+        let point = Point::new(1.3, 2.7, -0.4);
+        let nearby = point + Vector::new(0.001, 0.001, 0.001);
+
+        assert!((perlin.noise(&point) - perlin.noise(&nearby)).abs() < 0.01);
+    }
+}
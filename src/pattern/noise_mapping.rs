@@ -0,0 +1,103 @@
+use std::f64::consts::PI;
+
+use crate::prelude::*;
+
+/// The ways noise can be turned into a place along the color ramp of a `NoisePattern`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NoiseMapping {
+    /// Soft bands running along the x axis, going through the ramp and back every 2 units. The
+    /// turbulence twists them around, by up to `turbulence`, into the veins of marble.
+    Marble { turbulence: f64 },
+    /// Rings around the y axis, one every unit, going through the ramp from the inside out. The
+    /// noise pushes them in or out, by up to `turbulence`, like the grain of wood.
+    Wood { turbulence: f64 },
+    /// The noise as it is, for soft and blotchy things like clouds, smoke or dirt.
+    Clouds,
+}
+
+impl NoiseMapping {
+    /// Where on the ramp, from 0 to 1, a point in pattern space ends up.
+    pub fn ramp_position_at(&self, point: &Point, perlin: &Perlin, octaves: usize) -> f64 {
+        match self {
+            NoiseMapping::Marble { turbulence } => {
+                let offset = turbulence * perlin.turbulence(point, octaves);
+                let band = ((point.x() + offset) * PI).sin();
+
+                0.5 + 0.5 * band
+            }
+            NoiseMapping::Wood { turbulence } => {
+                let distance = (point.x() * point.x() + point.z() * point.z()).sqrt()
+                    + turbulence * perlin.fractal(point, octaves);
+
+                distance.rem_euclid(1.0)
+            }
+            NoiseMapping::Clouds => 0.5 + 0.5 * perlin.fractal(point, octaves),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn without_turbulence_the_bands_of_marble_are_straight() {
+        let mapping = NoiseMapping::Marble { turbulence: 0.0 };
+        let perlin = Perlin::new(0);
+
+        let cases = [
+            (Point::new(0.5, 3.0, -2.0), 1.0),
+            (Point::new(1.5, -1.0, 7.0), 0.0),
+            (ORIGIN, 0.5),
+        ];
+
+        for (point, expected) in cases {
+            let position = mapping.ramp_position_at(&point, &perlin, 4);
+            assert!(close_enough(&position, &expected), "{:?}", point);
+        }
+    }
+
+    #[test]
+    fn without_turbulence_the_rings_of_wood_are_round() {
+        let mapping = NoiseMapping::Wood { turbulence: 0.0 };
+        let perlin = Perlin::new(0);
+
+        let cases = [
+            (Point::new(0.5, 0.3, 0.0), 0.5),
+            (Point::new(0.0, -4.0, 1.5), 0.5),
+            (Point::new(1.25, 9.0, 0.0), 0.25),
+        ];
+
+        for (point, expected) in cases {
+            let position = mapping.ramp_position_at(&point, &perlin, 2);
+            assert!(close_enough(&position, &expected), "{:?}", point);
+        }
+    }
+
+    #[test]
+    fn where_the_noise_is_zero_clouds_are_in_the_middle_of_the_ramp() {
+        let position = NoiseMapping::Clouds.ramp_position_at(&ORIGIN, &Perlin::new(0), 5);
+
+        assert_eq!(position, 0.5);
+    }
+
+    #[test]
+    fn turbulence_moves_points_along_the_ramp() {
+        let perlin = Perlin::new(0);
+        let point = Point::new(0.3, 0.7, 1.9);
+
+        let straight = NoiseMapping::Marble { turbulence: 0.0 };
+        let marble = NoiseMapping::Marble { turbulence: 5.0 };
+        assert_ne!(
+            marble.ramp_position_at(&point, &perlin, 4),
+            straight.ramp_position_at(&point, &perlin, 4)
+        );
+
+        let round = NoiseMapping::Wood { turbulence: 0.0 };
+        let wood = NoiseMapping::Wood { turbulence: 0.1 };
+        assert_ne!(
+            wood.ramp_position_at(&point, &perlin, 2),
+            round.ramp_position_at(&point, &perlin, 2)
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use crate::prelude::*;
+
+/// Perlin noise run through the colors of a ramp, with a mapping that decides what the noise
+/// looks like, from the veins of marble to the grain of wood.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoisePattern {
+    ramp: ColorRamp,
+    mapping: NoiseMapping,
+    octaves: usize,
+    perlin: Perlin,
+    transform: Transform,
+}
+
+impl NoisePattern {
+    pub fn new(ramp: ColorRamp, mapping: NoiseMapping, octaves: usize) -> Self {
+        NoisePattern {
+            ramp,
+            mapping,
+            octaves,
+            perlin: Perlin::new(0),
+            transform: Transform::new(IDENTITY_MATRIX),
+        }
+    }
+
+    pub fn set_seed(&mut self, seed: u64) {
+        self.perlin = Perlin::new(seed);
+    }
+}
+
+impl Pattern for NoisePattern {
+    fn color_at(&self, point: &Point) -> Color {
+        let pattern_space_point = self.transform.invert().unwrap() * *point;
+        let position =
+            self.mapping
+                .ramp_position_at(&pattern_space_point, &self.perlin, self.octaves);
+
+        self.ramp.color_at(position)
+    }
+}
+
+impl Eq for NoisePattern {}
+
+impl Transformable for NoisePattern {
+    fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn clouds() -> NoisePattern {
+        NoisePattern::new(ColorRamp::new(vec![BLACK, WHITE]), NoiseMapping::Clouds, 5)
+    }
+
+    #[test]
+    fn the_noise_goes_back_and_forth_along_the_ramp() {
+        let pattern = clouds();
+
+        let brightness: Vec<f64> = (0..100)
+            .map(|i| {
+                let point = Point::new(i as f64 * 0.23, 0.5, i as f64 * 0.17);
+                *pattern.color_at(&point).r()
+            })
+            .collect();
+
+        assert!(brightness.iter().any(|&b| b < 0.4));
+        assert!(brightness.iter().any(|&b| b > 0.6));
+    }
+
+    #[test]
+    fn the_same_seed_gives_the_same_noise() {
+        let mut first = clouds();
+        first.set_seed(4);
+        let mut second = clouds();
+        second.set_seed(4);
+
+        assert_eq!(first, second);
+        let point = Point::new(0.3, 0.7, 1.9);
+        assert_eq!(first.color_at(&point), second.color_at(&point));
+    }
+
+    #[test]
+    fn different_seeds_give_different_noise() {
+        let mut first = clouds();
+        first.set_seed(1);
+        let mut second = clouds();
+        second.set_seed(2);
+
+        let point = Point::new(0.3, 0.7, 1.9);
+        assert_ne!(first.color_at(&point), second.color_at(&point));
+    }
+
+    #[test]
+    fn the_noise_can_be_spread_out_with_a_transform() {
+        let mut pattern = NoisePattern::new(
+            ColorRamp::new(vec![BLACK, WHITE]),
+            NoiseMapping::Wood { turbulence: 0.0 },
+            2,
+        );
+        pattern.set_transform(Transform::scaling(2.0, 1.0, 2.0));
+
+        assert_eq!(
+            pattern.color_at(&Point::new(1.0, 0.0, 0.0)),
+            Color::new(0.5, 0.5, 0.5)
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use crate::prelude::*;
+
+/// Wraps another pattern and nudges each point around with Perlin noise before looking it up,
+/// which roughens up the clean edges of stripes, rings and the like.
+#[derive(Debug, PartialEq)]
+pub struct PerturbedPattern {
+    pattern: Box<dyn Pattern>,
+    // How far, at most, points get moved.
+    scale: f64,
+    octaves: usize,
+    perlin: Perlin,
+    transform: Transform,
+}
+
+impl PerturbedPattern {
+    pub fn new(pattern: Box<dyn Pattern>, scale: f64, octaves: usize) -> Self {
+        PerturbedPattern {
+            pattern,
+            scale,
+            octaves,
+            perlin: Perlin::new(0),
+            transform: Transform::new(IDENTITY_MATRIX),
+        }
+    }
+
+    pub fn set_seed(&mut self, seed: u64) {
+        self.perlin = Perlin::new(seed);
+    }
+}
+
+impl Pattern for PerturbedPattern {
+    fn color_at(&self, point: &Point) -> Color {
+        let pattern_space_point = self.transform.invert().unwrap() * *point;
+
+        // The noise for each axis is looked up far apart, so they don't all move the same way.
+        let jitter = Vector::new(
+            self.perlin.fractal(&pattern_space_point, self.octaves),
+            self.perlin.fractal(
+                &(pattern_space_point + Vector::new(31.4, 15.9, 26.5)),
+                self.octaves,
+            ),
+            self.perlin.fractal(
+                &(pattern_space_point + Vector::new(-58.9, 79.3, -23.8)),
+                self.octaves,
+            ),
+        );
+
+        self.pattern
+            .color_at(&(pattern_space_point + jitter * self.scale))
+    }
+}
+
+impl Eq for PerturbedPattern {}
+
+impl Transformable for PerturbedPattern {
+    fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn stripes() -> Box<dyn Pattern> {
+        Box::new(StripePattern::new(WHITE, BLACK))
+    }
+
+    #[test]
+    fn without_any_scale_the_wrapped_pattern_is_left_alone() {
+        let pattern = PerturbedPattern::new(stripes(), 0.0, 3);
+
+        for x in [0.1, 0.6, 0.99, 1.2, 1.9, -0.4] {
+            let point = Point::new(x, 0.3, -2.1);
+            assert_eq!(pattern.color_at(&point), stripes().color_at(&point));
+        }
+    }
+
+    #[test]
+    fn points_near_the_edges_get_moved_across_them() {
+        let pattern = PerturbedPattern::new(stripes(), 0.3, 2);
+
+        let points_near_edges: Vec<Point> = (0..50)
+            .map(|i| Point::new(1.0 + (i % 2) as f64 * 0.02 - 0.01, i as f64 * 0.31, 0.7))
+            .collect();
+
+        assert!(points_near_edges
+            .iter()
+            .any(|point| pattern.color_at(point) != stripes().color_at(point)));
+    }
+
+    #[test]
+    fn the_same_seed_gives_the_same_pattern() {
+        let mut first = PerturbedPattern::new(stripes(), 0.5, 2);
+        first.set_seed(9);
+        let mut second = PerturbedPattern::new(stripes(), 0.5, 2);
+        second.set_seed(9);
+
+        assert_eq!(first, second);
+        for i in 0..50 {
+            let point = Point::new(i as f64 * 0.17, i as f64 * 0.23, 0.0);
+            assert_eq!(first.color_at(&point), second.color_at(&point));
+        }
+    }
+}
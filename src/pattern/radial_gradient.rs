@@ -0,0 +1,92 @@
+use crate::prelude::*;
+
+/// Changes color with the distance from the y axis, going through the ramp every unit out from
+/// it. Good for glows on the ground and ripples.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RadialGradientPattern {
+    ramp: ColorRamp,
+    repeat: GradientRepeat,
+    transform: Transform,
+}
+
+impl RadialGradientPattern {
+    /// Past one unit out, the last color of the ramp carries on unless the repeat is changed.
+    pub fn new(ramp: ColorRamp) -> Self {
+        RadialGradientPattern {
+            ramp,
+            repeat: GradientRepeat::Clamp,
+            transform: Transform::new(IDENTITY_MATRIX),
+        }
+    }
+
+    pub fn set_repeat(&mut self, repeat: GradientRepeat) {
+        self.repeat = repeat;
+    }
+}
+
+impl Pattern for RadialGradientPattern {
+    fn color_at(&self, point: &Point) -> Color {
+        let pattern_space_point = self.transform.invert().unwrap() * *point;
+        let x = pattern_space_point.x();
+        let z = pattern_space_point.z();
+
+        let distance_from_axis = (x * x + z * z).sqrt();
+
+        self.ramp.color_at(self.repeat.apply(distance_from_axis))
+    }
+}
+
+impl Eq for RadialGradientPattern {}
+
+impl Transformable for RadialGradientPattern {
+    fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn pattern() -> RadialGradientPattern {
+        RadialGradientPattern::new(ColorRamp::new(vec![WHITE, BLACK]))
+    }
+
+    #[test]
+    fn the_color_changes_with_the_distance_from_the_y_axis() {
+        let pattern = pattern();
+
+        assert_eq!(pattern.color_at(&Point::new(0.0, 5.0, 0.0)), WHITE);
+        assert_eq!(
+            pattern.color_at(&Point::new(0.5, 0.0, 0.0)),
+            Color::new(0.5, 0.5, 0.5)
+        );
+        assert_eq!(
+            pattern.color_at(&Point::new(0.0, -2.0, 0.5)),
+            Color::new(0.5, 0.5, 0.5)
+        );
+        assert_eq!(
+            pattern.color_at(&Point::new(0.3, 0.0, 0.4)),
+            Color::new(0.5, 0.5, 0.5)
+        );
+        assert_eq!(pattern.color_at(&Point::new(4.0, 0.0, 0.0)), BLACK);
+    }
+
+    #[test]
+    fn the_ramp_can_repeat_further_out() {
+        let mut pattern = pattern();
+        pattern.set_repeat(GradientRepeat::Repeat);
+
+        assert_eq!(
+            pattern.color_at(&Point::new(1.25, 0.0, 0.0)),
+            Color::new(0.75, 0.75, 0.75)
+        );
+
+        pattern.set_repeat(GradientRepeat::Mirror);
+
+        assert_eq!(
+            pattern.color_at(&Point::new(1.25, 0.0, 0.0)),
+            Color::new(0.25, 0.25, 0.25)
+        );
+    }
+}
@@ -1,21 +1,25 @@
 use crate::prelude::*;
 
+/// Rings around the y axis, going through the colors of a ramp every 2 units out from it.
 #[derive(Debug, Clone, PartialEq)]
 pub struct RingPattern {
-    background: Color,
-    stripe: Color,
+    ramp: ColorRamp,
     transform: Transform,
 }
 
 impl RingPattern {
+    /// Rings one unit wide, switching between the two colors.
     pub fn new(background: Color, stripe: Color) -> Self {
+        let mut ramp = ColorRamp::from_stops(vec![(0.0, background), (0.5, stripe)]);
+        ramp.set_interpolation(RampInterpolation::Constant);
+
+        RingPattern::new_with_ramp(ramp)
+    }
+
+    pub fn new_with_ramp(ramp: ColorRamp) -> Self {
         let transform = Transform::new(IDENTITY_MATRIX);
 
-        RingPattern {
-            background,
-            stripe,
-            transform,
-        }
+        RingPattern { ramp, transform }
     }
 }
 
@@ -27,11 +31,9 @@ impl Pattern for RingPattern {
 
         // The hypotenuse of the triangle created by X and Z will be the distance from the center
         let distance_from_center = (x.powi(2) + z.powi(2)).sqrt();
-        if distance_from_center.floor().rem_euclid(2.0) == 0.0 {
-            self.background.clone()
-        } else {
-            self.stripe.clone()
-        }
+
+        self.ramp
+            .color_at((distance_from_center / 2.0).rem_euclid(1.0))
     }
 }
 
@@ -69,4 +71,19 @@ mod test {
         // A point on that first ring...
         assert_eq!(pattern.color_at(&Point::new(1.416, 0.0, 1.416)), BLACK);
     }
+
+    #[test]
+    fn rings_can_go_through_the_colors_of_a_ramp() {
+        let pattern = RingPattern::new_with_ramp(ColorRamp::new(vec![WHITE, BLACK]));
+
+        assert_eq!(pattern.color_at(&Point::new(0.0, 0.0, 0.0)), WHITE);
+        assert_eq!(
+            pattern.color_at(&Point::new(1.0, 0.0, 0.0)),
+            Color::new(0.5, 0.5, 0.5)
+        );
+        assert_eq!(
+            pattern.color_at(&Point::new(0.0, 0.0, 2.5)),
+            Color::new(0.75, 0.75, 0.75)
+        );
+    }
 }
@@ -0,0 +1,91 @@
+use crate::prelude::*;
+
+/// Changes color with the distance from the center of the pattern, going through the ramp every
+/// unit out from it. Good for glows and halos.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SphericalGradientPattern {
+    ramp: ColorRamp,
+    repeat: GradientRepeat,
+    transform: Transform,
+}
+
+impl SphericalGradientPattern {
+    /// Past one unit out, the last color of the ramp carries on unless the repeat is changed.
+    pub fn new(ramp: ColorRamp) -> Self {
+        SphericalGradientPattern {
+            ramp,
+            repeat: GradientRepeat::Clamp,
+            transform: Transform::new(IDENTITY_MATRIX),
+        }
+    }
+
+    pub fn set_repeat(&mut self, repeat: GradientRepeat) {
+        self.repeat = repeat;
+    }
+}
+
+impl Pattern for SphericalGradientPattern {
+    fn color_at(&self, point: &Point) -> Color {
+        let pattern_space_point = self.transform.invert().unwrap() * *point;
+        let distance_from_center = (pattern_space_point - ORIGIN).magnitude();
+
+        self.ramp.color_at(self.repeat.apply(distance_from_center))
+    }
+}
+
+impl Eq for SphericalGradientPattern {}
+
+impl Transformable for SphericalGradientPattern {
+    fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn pattern() -> SphericalGradientPattern {
+        SphericalGradientPattern::new(ColorRamp::new(vec![WHITE, BLACK]))
+    }
+
+    #[test]
+    fn the_color_changes_with_the_distance_from_the_center() {
+        let pattern = pattern();
+
+        assert_eq!(pattern.color_at(&ORIGIN), WHITE);
+        assert_eq!(
+            pattern.color_at(&Point::new(0.0, 0.5, 0.0)),
+            Color::new(0.5, 0.5, 0.5)
+        );
+        assert_eq!(
+            pattern.color_at(&Point::new(0.3, 0.0, -0.4)),
+            Color::new(0.5, 0.5, 0.5)
+        );
+        assert_eq!(pattern.color_at(&Point::new(1.0, 1.0, 1.0)), BLACK);
+    }
+
+    #[test]
+    fn the_ramp_can_be_mirrored_further_out() {
+        let mut pattern = pattern();
+        pattern.set_repeat(GradientRepeat::Mirror);
+
+        assert_eq!(
+            pattern.color_at(&Point::new(0.0, 1.75, 0.0)),
+            Color::new(0.75, 0.75, 0.75)
+        );
+        assert_eq!(pattern.color_at(&Point::new(0.0, 0.0, 2.0)), WHITE);
+    }
+
+    #[test]
+    fn the_gradient_can_be_squashed_by_scaling_it() {
+        let mut pattern = pattern();
+        pattern.set_transform(Transform::scaling(2.0, 1.0, 1.0));
+
+        assert_eq!(
+            pattern.color_at(&Point::new(1.0, 0.0, 0.0)),
+            Color::new(0.5, 0.5, 0.5)
+        );
+        assert_eq!(pattern.color_at(&Point::new(0.0, 1.0, 0.0)), BLACK);
+    }
+}
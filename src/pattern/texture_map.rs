@@ -0,0 +1,83 @@
+use crate::prelude::*;
+
+/// Puts a 2D pattern onto a surface, by turning each point into (u, v) coordinates with a mapping.
+#[derive(Debug)]
+pub struct TextureMapPattern {
+    uv_pattern: Box<dyn UvPattern>,
+    mapping: UvMapping,
+    transform: Transform,
+}
+
+impl TextureMapPattern {
+    pub fn new(uv_pattern: Box<dyn UvPattern>, mapping: UvMapping) -> Self {
+        TextureMapPattern {
+            uv_pattern,
+            mapping,
+            transform: Transform::new(IDENTITY_MATRIX),
+        }
+    }
+}
+
+impl Pattern for TextureMapPattern {
+    fn color_at(&self, point: &Point) -> Color {
+        let pattern_space_point = self.transform.invert().unwrap() * *point;
+        let (u, v) = self.mapping.uv_at(&pattern_space_point);
+
+        self.uv_pattern.color_at_uv(u, v)
+    }
+}
+
+impl PartialEq for TextureMapPattern {
+    fn eq(&self, other: &Self) -> bool {
+        *self.uv_pattern == *other.uv_pattern
+            && self.mapping == other.mapping
+            && self.transform == other.transform
+    }
+}
+
+impl Eq for TextureMapPattern {}
+
+impl Transformable for TextureMapPattern {
+    fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Black on the left half and white on the right half, looking at it with v going up.
+    fn halves() -> Box<dyn UvPattern> {
+        Box::new(ImageTexture::new(2, 1, vec![BLACK, WHITE]))
+    }
+
+    #[test]
+    fn the_same_pattern_can_be_put_on_with_any_mapping() {
+        let cases = [
+            (UvMapping::Planar, Point::new(0.25, 0.0, 0.5), BLACK),
+            (UvMapping::Planar, Point::new(0.75, 0.0, 0.5), WHITE),
+            (UvMapping::Spherical, Point::new(1.0, 0.0, 0.0), BLACK),
+            (UvMapping::Spherical, Point::new(-1.0, 0.0, 0.0), WHITE),
+            (UvMapping::Cylindrical, Point::new(1.0, 3.5, 0.0), BLACK),
+            (UvMapping::Cylindrical, Point::new(-1.0, 3.5, 0.0), WHITE),
+            (UvMapping::Cube, Point::new(-0.5, 0.0, 1.0), BLACK),
+            (UvMapping::Cube, Point::new(1.0, 0.0, -0.5), WHITE),
+        ];
+
+        for (mapping, point, expected) in cases {
+            let pattern = TextureMapPattern::new(halves(), mapping);
+
+            assert_eq!(pattern.color_at(&point), expected, "{:?}", mapping);
+        }
+    }
+
+    #[test]
+    fn the_pattern_is_mapped_in_its_own_space() {
+        let mut pattern = TextureMapPattern::new(halves(), UvMapping::Planar);
+        pattern.set_transform(Transform::translation(0.5, 0.0, 0.0));
+
+        assert_eq!(pattern.color_at(&Point::new(0.75, 0.0, 0.5)), BLACK);
+        assert_eq!(pattern.color_at(&Point::new(0.25, 0.0, 0.5)), WHITE);
+    }
+}
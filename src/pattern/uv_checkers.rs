@@ -0,0 +1,106 @@
+use crate::prelude::*;
+
+/// A 2D checkerboard with `width` squares across and `height` squares up. Being laid out in
+/// (u, v) coordinates, the squares stay even all over curved shapes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UvCheckersPattern {
+    width: f64,
+    height: f64,
+    background: Color,
+    checker: Color,
+}
+
+impl UvCheckersPattern {
+    pub fn new(width: f64, height: f64, background: Color, checker: Color) -> Self {
+        UvCheckersPattern {
+            width,
+            height,
+            background,
+            checker,
+        }
+    }
+}
+
+impl UvPattern for UvCheckersPattern {
+    fn color_at_uv(&self, u: f64, v: f64) -> Color {
+        let column = (u * self.width).floor();
+        let row = (v * self.height).floor();
+
+        if (column + row).rem_euclid(2.0) == 0.0 {
+            self.background
+        } else {
+            self.checker
+        }
+    }
+}
+
+impl Eq for UvCheckersPattern {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn checkers_alternate_in_both_directions() {
+        let pattern = UvCheckersPattern::new(2.0, 2.0, BLACK, WHITE);
+
+        assert_eq!(pattern.color_at_uv(0.0, 0.0), BLACK);
+        assert_eq!(pattern.color_at_uv(0.5, 0.0), WHITE);
+        assert_eq!(pattern.color_at_uv(0.0, 0.5), WHITE);
+        assert_eq!(pattern.color_at_uv(0.5, 0.5), BLACK);
+        assert_eq!(pattern.color_at_uv(1.0, 1.0), BLACK);
+    }
+
+    #[test]
+    fn the_squares_can_have_different_counts_across_and_up() {
+        let pattern = UvCheckersPattern::new(4.0, 1.0, BLACK, WHITE);
+
+        assert_eq!(pattern.color_at_uv(0.1, 0.9), BLACK);
+        assert_eq!(pattern.color_at_uv(0.3, 0.9), WHITE);
+        assert_eq!(pattern.color_at_uv(0.6, 0.1), BLACK);
+        assert_eq!(pattern.color_at_uv(0.8, 0.1), WHITE);
+    }
+
+    #[test]
+    fn checkers_on_a_sphere_line_up_with_its_poles() {
+        let pattern = TextureMapPattern::new(
+            Box::new(UvCheckersPattern::new(16.0, 8.0, BLACK, WHITE)),
+            UvMapping::Spherical,
+        );
+
+        assert_eq!(pattern.color_at(&Point::new(0.4315, 0.4670, 0.7719)), WHITE);
+        assert_eq!(
+            pattern.color_at(&Point::new(-0.9654, 0.2552, -0.0534)),
+            BLACK
+        );
+        assert_eq!(pattern.color_at(&Point::new(0.1039, 0.7090, 0.6975)), WHITE);
+        assert_eq!(
+            pattern.color_at(&Point::new(-0.4986, -0.7856, -0.3663)),
+            BLACK
+        );
+        assert_eq!(
+            pattern.color_at(&Point::new(-0.0317, -0.9395, 0.3411)),
+            BLACK
+        );
+        assert_eq!(
+            pattern.color_at(&Point::new(0.4809, -0.7721, 0.4154)),
+            BLACK
+        );
+        assert_eq!(
+            pattern.color_at(&Point::new(0.0285, -0.9612, -0.2745)),
+            BLACK
+        );
+        assert_eq!(
+            pattern.color_at(&Point::new(-0.5734, -0.2162, -0.7903)),
+            WHITE
+        );
+        assert_eq!(
+            pattern.color_at(&Point::new(0.7688, -0.1470, 0.6223)),
+            BLACK
+        );
+        assert_eq!(
+            pattern.color_at(&Point::new(-0.7652, 0.2175, 0.6060)),
+            BLACK
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use std::f64::consts::PI;
+
+use crate::prelude::*;
+
+/// The ways a point in pattern space can be turned into (u, v) coordinates on a surface.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UvMapping {
+    /// Lays the pattern flat on the xz plane, repeating every unit in x (u) and z (v).
+    Planar,
+    /// Wraps the pattern around a sphere centered on the origin, like the lines of longitude (u)
+    /// and latitude (v) on a globe.
+    Spherical,
+    /// Wraps the pattern around the y axis (u), repeating every unit up it (v).
+    Cylindrical,
+    /// Puts the whole pattern on each of the six faces of a cube from -1 to 1, like the faces of
+    /// an unfolded box.
+    Cube,
+}
+
+impl UvMapping {
+    pub fn uv_at(&self, point: &Point) -> (f64, f64) {
+        match self {
+            UvMapping::Planar => planar_uv_at(point),
+            UvMapping::Spherical => spherical_uv_at(point),
+            UvMapping::Cylindrical => cylindrical_uv_at(point),
+            UvMapping::Cube => cube_uv_at(point),
+        }
+    }
+}
+
+fn planar_uv_at(point: &Point) -> (f64, f64) {
+    (point.x().rem_euclid(1.0), point.z().rem_euclid(1.0))
+}
+
+fn spherical_uv_at(point: &Point) -> (f64, f64) {
+    // The angle around the y axis, going from -π to π.
+    let theta = point.x().atan2(*point.z());
+
+    let radius = (*point - ORIGIN).magnitude();
+    // The angle down from the top of the sphere, going from 0 to π.
+    let phi = (point.y() / radius).acos();
+
+    // u goes anticlockwise when looking down on the sphere, starting from the back of it.
+    let raw_u = theta / (2.0 * PI);
+    let u = 1.0 - (raw_u + 0.5);
+
+    let v = 1.0 - phi / PI;
+
+    (u, v)
+}
+
+fn cylindrical_uv_at(point: &Point) -> (f64, f64) {
+    // Just like the spherical mapping around the y axis.
+    let theta = point.x().atan2(*point.z());
+    let raw_u = theta / (2.0 * PI);
+    let u = 1.0 - (raw_u + 0.5);
+
+    (u, point.y().rem_euclid(1.0))
+}
+
+// Each face is looked at from outside the cube, with u going to the right and v going up. The
+// faces around the sides have "up" towards +y, the top face has it towards +z and the bottom face
+// has it towards -z.
+fn cube_uv_at(point: &Point) -> (f64, f64) {
+    let (x, y, z) = (*point.x(), *point.y(), *point.z());
+    let largest = x.abs().max(y.abs()).max(z.abs());
+
+    let (u, v) = if largest == x {
+        (1.0 - z, y + 1.0)
+    } else if largest == -x {
+        (z + 1.0, y + 1.0)
+    } else if largest == y {
+        (x + 1.0, 1.0 - z)
+    } else if largest == -y {
+        (x + 1.0, z + 1.0)
+    } else if largest == z {
+        (x + 1.0, y + 1.0)
+    } else {
+        (1.0 - x, y + 1.0)
+    };
+
+    (u.rem_euclid(2.0) / 2.0, v.rem_euclid(2.0) / 2.0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::f64::consts::FRAC_1_SQRT_2;
+
+    #[test]
+    fn using_a_spherical_mapping_on_a_point() {
+        let cases = [
+            (Point::new(0.0, 0.0, -1.0), (0.0, 0.5)),
+            (Point::new(1.0, 0.0, 0.0), (0.25, 0.5)),
+            (Point::new(0.0, 0.0, 1.0), (0.5, 0.5)),
+            (Point::new(-1.0, 0.0, 0.0), (0.75, 0.5)),
+            (Point::new(0.0, 1.0, 0.0), (0.5, 1.0)),
+            (Point::new(0.0, -1.0, 0.0), (0.5, 0.0)),
+            (Point::new(FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0.0), (0.25, 0.75)),
+        ];
+
+        for (point, (expected_u, expected_v)) in cases {
+            let (u, v) = UvMapping::Spherical.uv_at(&point);
+
+            assert!(close_enough(&u, &expected_u), "{:?}", point);
+            assert!(close_enough(&v, &expected_v), "{:?}", point);
+        }
+    }
+
+    fn assert_uv(mapping: UvMapping, point: Point, expected_u: f64, expected_v: f64) {
+        let (u, v) = mapping.uv_at(&point);
+
+        assert!(
+            close_enough(&u, &expected_u) && close_enough(&v, &expected_v),
+            "{:?} at {:?} gave ({}, {}), expected ({}, {})",
+            mapping,
+            point,
+            u,
+            v,
+            expected_u,
+            expected_v
+        );
+    }
+
+    #[test]
+    fn using_a_planar_mapping_on_a_point() {
+        let cases = [
+            (Point::new(0.25, 0.0, 0.5), (0.25, 0.5)),
+            (Point::new(0.25, 0.0, -0.25), (0.25, 0.75)),
+            (Point::new(0.25, 0.5, -0.25), (0.25, 0.75)),
+            (Point::new(1.25, 0.0, 0.5), (0.25, 0.5)),
+            (Point::new(0.25, 0.0, -1.75), (0.25, 0.25)),
+            (Point::new(1.0, 0.0, -1.0), (0.0, 0.0)),
+            (Point::new(0.0, 0.0, 0.0), (0.0, 0.0)),
+        ];
+
+        for (point, (u, v)) in cases {
+            assert_uv(UvMapping::Planar, point, u, v);
+        }
+    }
+
+    #[test]
+    fn using_a_cylindrical_mapping_on_a_point() {
+        let cases = [
+            (Point::new(0.0, 0.0, -1.0), (0.0, 0.0)),
+            (Point::new(0.0, 0.5, -1.0), (0.0, 0.5)),
+            (Point::new(0.0, 1.0, -1.0), (0.0, 0.0)),
+            (Point::new(FRAC_1_SQRT_2, 0.5, -FRAC_1_SQRT_2), (0.125, 0.5)),
+            (Point::new(1.0, 0.5, 0.0), (0.25, 0.5)),
+            (Point::new(FRAC_1_SQRT_2, 0.5, FRAC_1_SQRT_2), (0.375, 0.5)),
+            (Point::new(0.0, -0.25, 1.0), (0.5, 0.75)),
+            (Point::new(-FRAC_1_SQRT_2, 0.5, FRAC_1_SQRT_2), (0.625, 0.5)),
+            (Point::new(-1.0, 1.25, 0.0), (0.75, 0.25)),
+            (
+                Point::new(-FRAC_1_SQRT_2, 0.5, -FRAC_1_SQRT_2),
+                (0.875, 0.5),
+            ),
+        ];
+
+        for (point, (u, v)) in cases {
+            assert_uv(UvMapping::Cylindrical, point, u, v);
+        }
+    }
+
+    #[test]
+    fn using_a_cube_mapping_on_each_face() {
+        let cases = [
+            // Front
+            (Point::new(-0.5, 0.5, 1.0), (0.25, 0.75)),
+            (Point::new(0.5, -0.5, 1.0), (0.75, 0.25)),
+            // Back
+            (Point::new(0.5, 0.5, -1.0), (0.25, 0.75)),
+            (Point::new(-0.5, -0.5, -1.0), (0.75, 0.25)),
+            // Left
+            (Point::new(-1.0, 0.5, -0.5), (0.25, 0.75)),
+            (Point::new(-1.0, -0.5, 0.5), (0.75, 0.25)),
+            // Right
+            (Point::new(1.0, 0.5, 0.5), (0.25, 0.75)),
+            (Point::new(1.0, -0.5, -0.5), (0.75, 0.25)),
+            // Top
+            (Point::new(-0.5, 1.0, -0.5), (0.25, 0.75)),
+            (Point::new(0.5, 1.0, 0.5), (0.75, 0.25)),
+            // Bottom
+            (Point::new(-0.5, -1.0, 0.5), (0.25, 0.75)),
+            (Point::new(0.5, -1.0, -0.5), (0.75, 0.25)),
+        ];
+
+        for (point, (u, v)) in cases {
+            assert_uv(UvMapping::Cube, point, u, v);
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use std::fmt::Debug;
+
+use dyn_eq::DynEq;
+
+use crate::prelude::*;
+
+/// A flat, 2D pattern that's looked up by (u, v) coordinates, which both go from 0 to 1 across it.
+/// It needs a `UvMapping` to get onto the surface of a shape.
+pub trait UvPattern: DynEq + Debug {
+    fn color_at_uv(&self, u: f64, v: f64) -> Color;
+}
+
+dyn_eq::eq_trait_object!(UvPattern);
@@ -0,0 +1,235 @@
+use crate::prelude::*;
+
+/// Which distance to the scattered points of a Worley pattern gets run through its ramp.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WorleyDistance {
+    /// To the nearest point, which is 0 at each point and grows towards the edges of its cell.
+    F1,
+    /// To the second nearest point, which gives a puffier look.
+    F2,
+    /// The difference between the two, which is 0 right on the edges between cells, like cracks
+    /// or the mortar between cobblestones.
+    F2MinusF1,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum WorleyColoring {
+    Ramp(WorleyDistance, ColorRamp),
+    Palette(Vec<Color>),
+}
+
+/// Cellular noise: one point is scattered in each unit cube of space, and every point belongs to
+/// the cell of the scattered point nearest to it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorleyPattern {
+    coloring: WorleyColoring,
+    seed: u64,
+    transform: Transform,
+}
+
+impl WorleyPattern {
+    pub fn new(distance: WorleyDistance, ramp: ColorRamp) -> Self {
+        WorleyPattern {
+            coloring: WorleyColoring::Ramp(distance, ramp),
+            seed: 0,
+            transform: Transform::new(IDENTITY_MATRIX),
+        }
+    }
+
+    /// Each cell gets one of the colors of the palette, picked by the seed.
+    pub fn new_with_palette(palette: Vec<Color>) -> Self {
+        assert!(!palette.is_empty(), "A palette needs at least one color");
+
+        WorleyPattern {
+            coloring: WorleyColoring::Palette(palette),
+            seed: 0,
+            transform: Transform::new(IDENTITY_MATRIX),
+        }
+    }
+
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = seed;
+    }
+
+    fn hash(&self, cell: [i64; 3], salt: u64) -> u64 {
+        hash_values(&[
+            self.seed,
+            cell[0] as u64,
+            cell[1] as u64,
+            cell[2] as u64,
+            salt,
+        ])
+    }
+
+    fn feature_point(&self, cell: [i64; 3]) -> Point {
+        let offset = |axis: u64| hash_to_unit(self.hash(cell, axis));
+
+        Point::new(
+            cell[0] as f64 + offset(0),
+            cell[1] as f64 + offset(1),
+            cell[2] as f64 + offset(2),
+        )
+    }
+
+    // The distances to the nearest and second nearest points, and the cell of the nearest one.
+    fn nearest_points(&self, point: &Point) -> (f64, f64, [i64; 3]) {
+        let home = [
+            point.x().floor() as i64,
+            point.y().floor() as i64,
+            point.z().floor() as i64,
+        ];
+
+        let mut nearest = f64::INFINITY;
+        let mut second_nearest = f64::INFINITY;
+        let mut nearest_cell = home;
+
+        // The two nearest points are all but always found in the cells around this one.
+        for x in -1..=1 {
+            for y in -1..=1 {
+                for z in -1..=1 {
+                    let cell = [home[0] + x, home[1] + y, home[2] + z];
+                    let distance = (self.feature_point(cell) - *point).magnitude();
+
+                    if distance < nearest {
+                        second_nearest = nearest;
+                        nearest = distance;
+                        nearest_cell = cell;
+                    } else if distance < second_nearest {
+                        second_nearest = distance;
+                    }
+                }
+            }
+        }
+
+        (nearest, second_nearest, nearest_cell)
+    }
+}
+
+impl Pattern for WorleyPattern {
+    fn color_at(&self, point: &Point) -> Color {
+        let pattern_space_point = self.transform.invert().unwrap() * *point;
+        let (f1, f2, cell) = self.nearest_points(&pattern_space_point);
+
+        match &self.coloring {
+            WorleyColoring::Ramp(distance, ramp) => ramp.color_at(match distance {
+                WorleyDistance::F1 => f1,
+                WorleyDistance::F2 => f2,
+                WorleyDistance::F2MinusF1 => f2 - f1,
+            }),
+            WorleyColoring::Palette(palette) => {
+                palette[(self.hash(cell, 3) % palette.len() as u64) as usize]
+            }
+        }
+    }
+}
+
+impl Eq for WorleyPattern {}
+
+impl Transformable for WorleyPattern {
+    fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn ramp() -> ColorRamp {
+        ColorRamp::new(vec![BLACK, WHITE])
+    }
+
+    fn sample_points() -> Vec<Point> {
+        (0..100)
+            .map(|i| {
+                let i = i as f64;
+                Point::new(i * 0.37 - 10.0, i * 0.13 + 2.5, i * -0.29 + 4.2)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn the_scattered_point_of_each_cell_is_inside_of_it() {
+        let pattern = WorleyPattern::new(WorleyDistance::F1, ramp());
+
+        for cell in [[0, 0, 0], [-3, 5, 2], [100, -100, 7]] {
+            let point = pattern.feature_point(cell);
+
+            assert_eq!(point.x().floor() as i64, cell[0]);
+            assert_eq!(point.y().floor() as i64, cell[1]);
+            assert_eq!(point.z().floor() as i64, cell[2]);
+        }
+    }
+
+    #[test]
+    fn f1_is_zero_right_on_a_scattered_point() {
+        let pattern = WorleyPattern::new(WorleyDistance::F1, ramp());
+        let point = pattern.feature_point([2, -1, 4]);
+
+        assert_eq!(pattern.color_at(&point), BLACK);
+    }
+
+    #[test]
+    fn the_second_nearest_point_is_never_nearer_than_the_nearest() {
+        let pattern = WorleyPattern::new(WorleyDistance::F1, ramp());
+
+        for point in sample_points() {
+            let (f1, f2, _cell) = pattern.nearest_points(&point);
+
+            assert!(f1 <= f2);
+        }
+    }
+
+    #[test]
+    fn each_distance_gets_run_through_the_ramp() {
+        let point = Point::new(0.3, 0.7, 1.9);
+        let (f1, f2, _cell) = WorleyPattern::new(WorleyDistance::F1, ramp()).nearest_points(&point);
+
+        let color_for = |distance| WorleyPattern::new(distance, ramp()).color_at(&point);
+
+        assert_eq!(color_for(WorleyDistance::F1), ramp().color_at(f1));
+        assert_eq!(color_for(WorleyDistance::F2), ramp().color_at(f2));
+        assert_eq!(
+            color_for(WorleyDistance::F2MinusF1),
+            ramp().color_at(f2 - f1)
+        );
+    }
+
+    #[test]
+    fn every_point_of_a_cell_gets_the_same_palette_color() {
+        let palette = vec![
+            Color::new(1.0, 0.0, 0.0),
+            Color::new(0.0, 1.0, 0.0),
+            Color::new(0.0, 0.0, 1.0),
+        ];
+        let pattern = WorleyPattern::new_with_palette(palette.clone());
+
+        let mut colors_seen = Vec::new();
+        for point in sample_points() {
+            let (_f1, _f2, cell) = pattern.nearest_points(&point);
+            let color = pattern.color_at(&point);
+
+            assert!(palette.contains(&color));
+            assert_eq!(pattern.color_at(&pattern.feature_point(cell)), color);
+
+            if !colors_seen.contains(&color) {
+                colors_seen.push(color);
+            }
+        }
+
+        assert_eq!(colors_seen.len(), palette.len());
+    }
+
+    #[test]
+    fn the_seed_moves_the_cells_around() {
+        let mut first = WorleyPattern::new(WorleyDistance::F1, ramp());
+        first.set_seed(1);
+        let mut second = WorleyPattern::new(WorleyDistance::F1, ramp());
+        second.set_seed(2);
+
+        assert_ne!(
+            first.feature_point([0, 0, 0]),
+            second.feature_point([0, 0, 0])
+        );
+    }
+}
@@ -0,0 +1,185 @@
+use crate::prelude::*;
+
+/// A flat, rectangular light with one corner at `corner` that stretches along the two edge
+/// vectors. It's split up into a grid of cells, and a point is lit from a spot in each of them,
+/// which is what gives its shadows their soft edges.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AreaLight {
+    corner: Point,
+    u_edge: Vector,
+    u_steps: usize,
+    v_edge: Vector,
+    v_steps: usize,
+    intensity: Color,
+    // Without jitter each spot is in the middle of its cell, which can make the edges of shadows
+    // look banded. With it, the spot moves around the cell from one point to the next.
+    jitter: bool,
+}
+
+impl AreaLight {
+    pub fn new(
+        corner: Point,
+        u_edge: Vector,
+        u_steps: usize,
+        v_edge: Vector,
+        v_steps: usize,
+        intensity: Color,
+    ) -> Self {
+        assert!(
+            u_steps > 0 && v_steps > 0,
+            "An area light needs at least one step along each of its edges"
+        );
+
+        AreaLight {
+            corner,
+            u_edge,
+            u_steps,
+            v_edge,
+            v_steps,
+            intensity,
+            jitter: false,
+        }
+    }
+
+    pub fn set_jitter(&mut self, jitter: bool) {
+        self.jitter = jitter;
+    }
+
+    /// Finds the spot in the cell at (u, v) that the point is lit from.
+    pub fn point_on_light(&self, u: usize, v: usize, point: &Point) -> Point {
+        let (u_offset, v_offset) = if self.jitter {
+            (jitter(point, u, v, 0), jitter(point, u, v, 1))
+        } else {
+            (0.5, 0.5)
+        };
+
+        self.corner
+            + self.u_edge * ((u as f64 + u_offset) / self.u_steps as f64)
+            + self.v_edge * ((v as f64 + v_offset) / self.v_steps as f64)
+    }
+}
+
+impl Eq for AreaLight {}
+
+impl Light for AreaLight {
+    fn intensity(&self) -> &Color {
+        &self.intensity
+    }
+
+    fn samples_for(&self, point: &Point) -> Vec<LightSample> {
+        let mut samples = Vec::with_capacity(self.u_steps * self.v_steps);
+
+        for v in 0..self.v_steps {
+            for u in 0..self.u_steps {
+                let point_to_light_vector = self.point_on_light(u, v, point) - *point;
+
+                samples.push(LightSample::new(
+                    point_to_light_vector.normalize(),
+                    point_to_light_vector.magnitude(),
+                    self.intensity,
+                ));
+            }
+        }
+
+        samples
+    }
+}
+
+// Gives a number between 0 and 1 that looks random, but is always the same for the same point and
+// cell, so that renders don't change from one run to the next.
+fn jitter(point: &Point, u: usize, v: usize, axis: u64) -> f64 {
+    hash_to_unit(hash_values(&[
+        point.x().to_bits(),
+        point.y().to_bits(),
+        point.z().to_bits(),
+        u as u64,
+        v as u64,
+        axis,
+    ]))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn light() -> AreaLight {
+        AreaLight::new(
+            ORIGIN,
+            Vector::new(2.0, 0.0, 0.0),
+            4,
+            Vector::new(0.0, 0.0, 1.0),
+            2,
+            WHITE,
+        )
+    }
+
+    #[test]
+    fn without_jitter_a_point_is_lit_from_the_middle_of_each_cell() {
+        let light = light();
+
+        let cases = [
+            (0, 0, Point::new(0.25, 0.0, 0.25)),
+            (1, 0, Point::new(0.75, 0.0, 0.25)),
+            (0, 1, Point::new(0.25, 0.0, 0.75)),
+            (2, 0, Point::new(1.25, 0.0, 0.25)),
+            (3, 1, Point::new(1.75, 0.0, 0.75)),
+        ];
+
+        for (u, v, expected) in cases {
+            assert_eq!(light.point_on_light(u, v, &ORIGIN), expected);
+        }
+    }
+
+    #[test]
+    fn there_is_a_sample_for_every_cell() {
+        let point = Point::new(1.0, -2.0, 0.5);
+        let samples = light().samples_for(&point);
+
+        assert_eq!(samples.len(), 8);
+
+        let first = &samples[0];
+        let to_light = Point::new(0.25, 0.0, 0.25) - point;
+        assert_eq!(*first.direction(), to_light.normalize());
+        assert!(close_enough(&first.distance(), &to_light.magnitude()));
+    }
+
+    #[test]
+    fn with_jitter_a_point_is_lit_from_somewhere_in_each_cell() {
+        let mut light = light();
+        light.set_jitter(true);
+
+        let points = [
+            ORIGIN,
+            Point::new(3.0, 1.0, -2.0),
+            Point::new(-0.5, 7.0, 4.0),
+        ];
+
+        for point in points {
+            for (u, v) in [(0, 0), (3, 1), (1, 1)] {
+                let spot = light.point_on_light(u, v, &point);
+
+                assert!(*spot.x() >= u as f64 * 0.5 && *spot.x() <= (u + 1) as f64 * 0.5);
+                assert!(*spot.z() >= v as f64 * 0.5 && *spot.z() <= (v + 1) as f64 * 0.5);
+                assert_eq!(*spot.y(), 0.0);
+
+                // The same point always gets the same spot.
+                assert_eq!(spot, light.point_on_light(u, v, &point));
+            }
+        }
+
+        assert!(light.point_on_light(0, 0, &points[0]) != light.point_on_light(0, 0, &points[1]));
+    }
+
+    #[test]
+    #[should_panic(expected = "An area light needs at least one step along each of its edges")]
+    fn an_area_light_without_any_cells_cannot_be_made() {
+        AreaLight::new(
+            ORIGIN,
+            Vector::new(2.0, 0.0, 0.0),
+            0,
+            Vector::new(0.0, 0.0, 1.0),
+            2,
+            WHITE,
+        );
+    }
+}
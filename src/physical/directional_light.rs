@@ -0,0 +1,55 @@
+use crate::prelude::*;
+
+/// A light so far away (like the sun) that its rays are all parallel, going in `direction`. It has
+/// no position, so nothing is ever on the far side of it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DirectionalLight {
+    intensity: Color,
+    direction: Vector,
+}
+
+impl DirectionalLight {
+    pub fn new(intensity: Color, direction: Vector) -> Self {
+        DirectionalLight {
+            intensity,
+            direction: direction.normalize(),
+        }
+    }
+}
+
+impl Eq for DirectionalLight {}
+
+impl Light for DirectionalLight {
+    fn intensity(&self) -> &Color {
+        &self.intensity
+    }
+
+    fn samples_for(&self, _point: &Point) -> Vec<LightSample> {
+        vec![LightSample::new(
+            -self.direction,
+            f64::INFINITY,
+            self.intensity,
+        )]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn every_point_is_lit_from_the_same_direction() {
+        let light = DirectionalLight::new(WHITE, Vector::new(1.0, -1.0, 0.0));
+
+        for point in [ORIGIN, Point::new(100.0, -3.0, 7.0)] {
+            let samples = light.samples_for(&point);
+
+            assert_eq!(samples.len(), 1);
+            assert_eq!(
+                *samples[0].direction(),
+                Vector::new(-1.0, 1.0, 0.0).normalize()
+            );
+            assert_eq!(samples[0].distance(), f64::INFINITY);
+        }
+    }
+}
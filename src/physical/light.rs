@@ -0,0 +1,48 @@
+use std::fmt::Debug;
+
+use dyn_eq::DynEq;
+
+use crate::prelude::*;
+
+/// Something that lights up the world. A light can reach a point from more than one place (like
+/// an area light does), so it gives back a sample for each of them.
+pub trait Light: DynEq + Debug {
+    fn intensity(&self) -> &Color;
+
+    /// Gets each of the ways the light reaches the point. Shading averages the diffuse and
+    /// specular parts over all of them.
+    fn samples_for(&self, point: &Point) -> Vec<LightSample>;
+}
+
+dyn_eq::eq_trait_object!(Light);
+
+/// One of the places a light reaches a point from.
+#[derive(Clone, Copy, Debug)]
+pub struct LightSample {
+    // From the point towards the light, always normalized.
+    direction: Vector,
+    distance: f64,
+    intensity: Color,
+}
+
+impl LightSample {
+    pub fn new(direction: Vector, distance: f64, intensity: Color) -> Self {
+        LightSample {
+            direction,
+            distance,
+            intensity,
+        }
+    }
+
+    pub fn direction(&self) -> &Vector {
+        &self.direction
+    }
+
+    pub fn distance(&self) -> f64 {
+        self.distance
+    }
+
+    pub fn intensity(&self) -> &Color {
+        &self.intensity
+    }
+}
@@ -1,11 +1,22 @@
+use std::rc::Rc;
+
 use crate::{
     pattern::{FlatPattern, Pattern},
     prelude::*,
 };
 
-#[derive(Debug)]
+/// Which space a material's pattern is evaluated in. In object space the pattern sticks to the
+/// shape as it's moved around, while in world space the shape moves through the pattern instead.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PatternSpace {
+    Object,
+    World,
+}
+
+#[derive(Debug, Clone)]
 pub struct Material {
-    pattern: Box<dyn Pattern>,
+    pattern: Rc<dyn Pattern>,
+    pattern_space: PatternSpace,
     ambient: f64,
     diffuse: f64,
     specular: f64,
@@ -13,11 +24,25 @@ pub struct Material {
     reflective: f64,
     refractive_index: f64,
     transparency: f64,
+    // Makes a transparent material block light completely, like everything used to.
+    opaque_shadow: bool,
 }
 
 impl Material {
-    pub fn color_at(&self, point: &Point) -> Color {
-        self.pattern.color_at(point)
+    /// Finds the color at a point on the surface of the object of the `hit`. The pattern moves the
+    /// point into its own space, so when the pattern is in object space the point goes through the
+    /// object's transform (and those of any groups it's in) first.
+    pub fn color_at(&self, world_space_point: &Point, hit: &Intersection) -> Color {
+        match self.pattern_space {
+            PatternSpace::Object => self
+                .pattern
+                .color_at(&hit.to_object_space(*world_space_point)),
+            PatternSpace::World => self.pattern.color_at(world_space_point),
+        }
+    }
+
+    pub fn pattern_space(&self) -> &PatternSpace {
+        &self.pattern_space
     }
 
     pub fn ambient(&self) -> f64 {
@@ -47,10 +72,16 @@ impl Material {
     pub fn transparency(&self) -> &f64 {
         &self.transparency
     }
+
+    /// Whether shapes with this material cast a solid shadow, even if they're transparent.
+    pub fn opaque_shadow(&self) -> bool {
+        self.opaque_shadow
+    }
 }
 
 pub struct MaterialBuilder {
     pattern: Box<dyn Pattern>,
+    pattern_space: PatternSpace,
     ambient: f64,
     diffuse: f64,
     specular: f64,
@@ -58,12 +89,14 @@ pub struct MaterialBuilder {
     reflective: f64,
     refractive_index: f64,
     transparency: f64,
+    opaque_shadow: bool,
 }
 
 impl MaterialBuilder {
     pub fn new() -> Self {
         MaterialBuilder {
             pattern: Box::new(FlatPattern::new(Color::new(1.0, 1.0, 1.0))),
+            pattern_space: PatternSpace::Object,
             ambient: 0.1,
             diffuse: 0.9,
             specular: 0.9,
@@ -71,6 +104,7 @@ impl MaterialBuilder {
             reflective: 0.0,
             refractive_index: 1.0,
             transparency: 0.0,
+            opaque_shadow: false,
         }
     }
 
@@ -79,6 +113,11 @@ impl MaterialBuilder {
         self
     }
 
+    pub fn pattern_space(mut self, pattern_space: PatternSpace) -> Self {
+        self.pattern_space = pattern_space;
+        self
+    }
+
     pub fn flat_color(mut self, color: Color) -> Self {
         self.pattern = Box::new(FlatPattern::new(color));
         self
@@ -119,9 +158,15 @@ impl MaterialBuilder {
         self
     }
 
+    pub fn opaque_shadow(mut self, opaque_shadow: bool) -> Self {
+        self.opaque_shadow = opaque_shadow;
+        self
+    }
+
     pub fn build(self) -> Material {
         Material {
-            pattern: self.pattern,
+            pattern: Rc::from(self.pattern),
+            pattern_space: self.pattern_space,
             ambient: self.ambient,
             diffuse: self.diffuse,
             specular: self.specular,
@@ -129,6 +174,7 @@ impl MaterialBuilder {
             reflective: self.reflective,
             refractive_index: self.refractive_index,
             transparency: self.transparency,
+            opaque_shadow: self.opaque_shadow,
         }
     }
 }
@@ -136,10 +182,12 @@ impl MaterialBuilder {
 impl PartialEq for Material {
     fn eq(&self, other: &Self) -> bool {
         return self.pattern.eq(&other.pattern)
+            && self.pattern_space == other.pattern_space
             && self.ambient == other.ambient
             && self.diffuse == other.diffuse
             && self.specular == other.specular
-            && self.shininess == other.shininess;
+            && self.shininess == other.shininess
+            && self.opaque_shadow == other.opaque_shadow;
     }
 }
 
@@ -158,5 +206,44 @@ mod test {
         assert_eq!(default_material.reflective, 0.0);
         assert_eq!(default_material.refractive_index, 1.0);
         assert_eq!(default_material.transparency, 0.0);
+        assert_eq!(default_material.pattern_space, PatternSpace::Object);
+        assert!(!default_material.opaque_shadow);
+    }
+
+    fn striped_sphere_hit(pattern_space: PatternSpace) -> (Rc<dyn Shape>, Ray) {
+        let mut pattern = StripePattern::new(WHITE, BLACK);
+        pattern.set_transform(Transform::scaling(0.5, 1.0, 1.0));
+
+        let mut sphere = Sphere::new();
+        sphere.set_transform(Transform::translation(0.5, 0.0, 0.0));
+        sphere.set_material(
+            MaterialBuilder::new()
+                .pattern(Box::new(pattern))
+                .pattern_space(pattern_space)
+                .build(),
+        );
+
+        // This ray hits the sphere right at the start of its first black stripe in object space.
+        let ray = Ray::new(Point::new(1.0, 0.0, -5.0), POSITIVE_Z);
+
+        (Rc::new(sphere), ray)
+    }
+
+    #[test]
+    fn a_pattern_in_object_space_follows_the_shape_and_its_own_transform() {
+        let (sphere, ray) = striped_sphere_hit(PatternSpace::Object);
+        let hit = determine_hit(Intersection::of(&sphere, &ray)).unwrap();
+
+        // The point is at x = 0.5 in object space, which is x = 1 once the stripes are scaled.
+        assert_eq!(hit.material().color_at(&hit.point(), &hit), BLACK);
+    }
+
+    #[test]
+    fn a_pattern_in_world_space_ignores_the_transform_of_the_shape() {
+        let (sphere, ray) = striped_sphere_hit(PatternSpace::World);
+        let hit = determine_hit(Intersection::of(&sphere, &ray)).unwrap();
+
+        // The point is at x = 1 in world space, which is x = 2 once the stripes are scaled.
+        assert_eq!(hit.material().color_at(&hit.point(), &hit), WHITE);
     }
 }
@@ -1,7 +1,15 @@
+mod area_light;
+mod directional_light;
+mod light;
 mod material;
 mod point_light;
+mod spot_light;
 mod world;
 
+pub use area_light::*;
+pub use directional_light::*;
+pub use light::*;
 pub use material::*;
 pub use point_light::*;
+pub use spot_light::*;
 pub use world::*;
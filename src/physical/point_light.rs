@@ -1,9 +1,42 @@
 use crate::prelude::*;
 
-#[derive(Clone, Copy, Debug)]
+/// How the intensity of a light drops off the further away from it a point is.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum Attenuation {
+    /// The light is just as bright at any distance.
+    #[default]
+    None,
+    /// The light falls off with the square of the distance, like it does in the real world.
+    InverseSquare,
+    /// The light is divided by `constant + linear·d + quadratic·d²` at a distance `d`, which gives
+    /// more control over how quickly it fades.
+    Coefficients {
+        constant: f64,
+        linear: f64,
+        quadratic: f64,
+    },
+}
+
+impl Attenuation {
+    /// What the intensity of the light gets multiplied by at the given distance.
+    pub fn factor_at(&self, distance: f64) -> f64 {
+        match self {
+            Attenuation::None => 1.0,
+            Attenuation::InverseSquare => 1.0 / distance.powi(2),
+            Attenuation::Coefficients {
+                constant,
+                linear,
+                quadratic,
+            } => 1.0 / (constant + linear * distance + quadratic * distance.powi(2)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct PointLight {
     intensity: Color,
     position: Point,
+    attenuation: Attenuation,
 }
 
 impl PointLight {
@@ -11,14 +44,70 @@ impl PointLight {
         PointLight {
             intensity,
             position,
+            attenuation: Attenuation::None,
         }
     }
 
-    pub fn intensity(&self) -> &Color {
+    pub fn set_attenuation(&mut self, attenuation: Attenuation) {
+        self.attenuation = attenuation;
+    }
+}
+
+impl Eq for PointLight {}
+
+impl Light for PointLight {
+    fn intensity(&self) -> &Color {
         &self.intensity
     }
 
-    pub fn position(&self) -> &Point {
-        &self.position
+    fn samples_for(&self, point: &Point) -> Vec<LightSample> {
+        let point_to_light_vector = self.position - *point;
+        let distance = point_to_light_vector.magnitude();
+
+        vec![LightSample::new(
+            point_to_light_vector.normalize(),
+            distance,
+            self.intensity * self.attenuation.factor_at(distance),
+        )]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn without_attenuation_a_point_light_is_as_bright_everywhere() {
+        let light = PointLight::new(WHITE, ORIGIN);
+
+        for point in [Point::new(0.0, 1.0, 0.0), Point::new(0.0, 0.0, 100.0)] {
+            assert_eq!(*light.samples_for(&point)[0].intensity(), WHITE);
+        }
+    }
+
+    #[test]
+    fn a_point_light_with_inverse_square_attenuation() {
+        let mut light = PointLight::new(WHITE, ORIGIN);
+        light.set_attenuation(Attenuation::InverseSquare);
+
+        let sample = light.samples_for(&Point::new(0.0, 2.0, 0.0))[0];
+        assert_eq!(*sample.intensity(), WHITE * 0.25);
+
+        let sample = light.samples_for(&Point::new(0.0, 0.0, -10.0))[0];
+        assert_eq!(*sample.intensity(), WHITE * 0.01);
+    }
+
+    #[test]
+    fn a_point_light_with_attenuation_coefficients() {
+        let mut light = PointLight::new(Color::new(1.0, 0.5, 0.0), ORIGIN);
+        light.set_attenuation(Attenuation::Coefficients {
+            constant: 1.0,
+            linear: 0.5,
+            quadratic: 0.25,
+        });
+
+        // 1 + 0.5·2 + 0.25·4 = 3
+        let sample = light.samples_for(&Point::new(2.0, 0.0, 0.0))[0];
+        assert_eq!(*sample.intensity(), Color::new(1.0, 0.5, 0.0) * (1.0 / 3.0));
     }
 }
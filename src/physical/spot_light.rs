@@ -0,0 +1,141 @@
+use crate::prelude::*;
+
+/// A light at a position that only shines in a cone around its `direction`. Inside the
+/// `inner_angle` the light is at full intensity, and between that and the `outer_angle` it fades
+/// smoothly away to nothing. Both angles are measured from the direction, in radians.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SpotLight {
+    intensity: Color,
+    position: Point,
+    direction: Vector,
+    inner_angle: f64,
+    outer_angle: f64,
+}
+
+impl SpotLight {
+    pub fn new(
+        intensity: Color,
+        position: Point,
+        direction: Vector,
+        inner_angle: f64,
+        outer_angle: f64,
+    ) -> Self {
+        SpotLight {
+            intensity,
+            position,
+            direction: direction.normalize(),
+            inner_angle,
+            outer_angle,
+        }
+    }
+
+    /// How much of the light's intensity gets to the point, from 1 inside the inner cone to 0
+    /// outside the outer one.
+    pub fn falloff_at(&self, point: &Point) -> f64 {
+        let light_to_point_vector = (*point - self.position).normalize();
+        let cosine = dot(&light_to_point_vector, &self.direction);
+
+        let inner_cosine = self.inner_angle.cos();
+        let outer_cosine = self.outer_angle.cos();
+
+        if cosine >= inner_cosine {
+            return 1.0;
+        }
+
+        if cosine <= outer_cosine {
+            return 0.0;
+        }
+
+        let amount = (cosine - outer_cosine) / (inner_cosine - outer_cosine);
+        amount * amount * (3.0 - 2.0 * amount)
+    }
+}
+
+impl Eq for SpotLight {}
+
+impl Light for SpotLight {
+    fn intensity(&self) -> &Color {
+        &self.intensity
+    }
+
+    fn samples_for(&self, point: &Point) -> Vec<LightSample> {
+        let point_to_light_vector = self.position - *point;
+
+        vec![LightSample::new(
+            point_to_light_vector.normalize(),
+            point_to_light_vector.magnitude(),
+            self.intensity * self.falloff_at(point),
+        )]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::f64::consts::PI;
+
+    fn light() -> SpotLight {
+        SpotLight::new(
+            WHITE,
+            Point::new(0.0, 10.0, 0.0),
+            NEGATIVE_Y,
+            PI / 8.0,
+            PI / 4.0,
+        )
+    }
+
+    #[test]
+    fn a_point_inside_the_inner_cone_gets_all_of_the_light() {
+        let light = light();
+
+        assert_eq!(light.falloff_at(&ORIGIN), 1.0);
+        assert_eq!(light.falloff_at(&Point::new(2.0, 0.0, 2.0)), 1.0);
+    }
+
+    #[test]
+    fn a_point_outside_the_outer_cone_gets_none_of_the_light() {
+        let light = light();
+
+        assert_eq!(light.falloff_at(&Point::new(11.0, 0.0, 0.0)), 0.0);
+        assert_eq!(light.falloff_at(&Point::new(0.0, 20.0, 0.0)), 0.0);
+
+        let sample = light.samples_for(&Point::new(0.0, 0.0, 11.0))[0];
+        assert_eq!(*sample.intensity(), BLACK);
+    }
+
+    #[test]
+    fn the_light_fades_smoothly_between_the_cones() {
+        let light = light();
+
+        let mut last_falloff = 1.0;
+        for step in 1..20 {
+            let angle = PI / 8.0 + (PI / 8.0) * step as f64 / 20.0;
+            let point = Point::new(10.0 * angle.tan(), 0.0, 0.0);
+            let falloff = light.falloff_at(&point);
+
+            assert!(falloff > 0.0 && falloff < 1.0);
+            assert!(falloff < last_falloff);
+            last_falloff = falloff;
+        }
+
+        // Halfway between the cones' cosines is halfway through the fade.
+        let halfway_cosine = ((PI / 8.0).cos() + (PI / 4.0).cos()) / 2.0;
+        let angle = halfway_cosine.acos();
+        let point = Point::new(10.0 * angle.tan(), 0.0, 0.0);
+        assert!(close_enough(&light.falloff_at(&point), &0.5));
+    }
+
+    #[test]
+    fn a_spot_light_is_sampled_from_its_position() {
+        let point = Point::new(1.0, 0.0, 0.0);
+        let samples = light().samples_for(&point);
+
+        assert_eq!(samples.len(), 1);
+        assert_eq!(
+            *samples[0].direction(),
+            (Point::new(0.0, 10.0, 0.0) - point).normalize()
+        );
+        assert!(close_enough(&samples[0].distance(), &101.0_f64.sqrt()));
+        assert_eq!(*samples[0].intensity(), WHITE);
+    }
+}
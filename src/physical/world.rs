@@ -1,18 +1,25 @@
 use crate::prelude::*;
-use std::rc::Rc;
+use std::{cell::OnceCell, rc::Rc};
 
 pub struct World {
-    light: PointLight,
+    lights: Vec<Rc<dyn Light>>,
     shapes: Vec<Rc<dyn Shape>>,
     background: Color,
+    // The world is finalized once the first ray is cast into it, which is when this gets built.
+    // Changing the shapes afterwards throws it away so that it can be built again.
+    bvh: OnceCell<Bvh>,
 }
 
 impl World {
     pub fn new() -> Self {
         World {
-            light: PointLight::new(Color::new(1.0, 1.0, 1.0), Point::new(-10.0, 10.0, -10.0)),
+            lights: vec![Rc::new(PointLight::new(
+                Color::new(1.0, 1.0, 1.0),
+                Point::new(-10.0, 10.0, -10.0),
+            ))],
             shapes: Vec::new(),
             background: BLACK,
+            bvh: OnceCell::new(),
         }
     }
 
@@ -36,9 +43,13 @@ impl World {
         shapes.push(Rc::new(second_sphere));
 
         World {
-            light: PointLight::new(Color::new(1.0, 1.0, 1.0), Point::new(-10.0, 10.0, -10.0)),
+            lights: vec![Rc::new(PointLight::new(
+                Color::new(1.0, 1.0, 1.0),
+                Point::new(-10.0, 10.0, -10.0),
+            ))],
             shapes,
             background: BLACK,
+            bvh: OnceCell::new(),
         }
     }
 
@@ -48,11 +59,18 @@ impl World {
     {
         let mut intersections: Vec<Intersection<'_>> = Vec::new();
 
-        for object in &self.shapes {
+        let bvh = self.bvh.get_or_init(|| Bvh::new(&self.shapes));
+
+        for object in bvh.shapes_along(ray) {
             let mut these_intersections = Intersection::of(object, ray);
             intersections.append(&mut these_intersections);
         }
 
+        // Shapes can be hidden from some kinds of rays. The intersections are checked rather than
+        // the shapes, since it's the shapes inside of groups that get intersected.
+        intersections
+            .retain(|intersection| intersection.object().visibility().is_seen_by(ray.kind()));
+
         intersections.sort_by(|a, b| a.t().total_cmp(&b.t()));
 
         intersections
@@ -66,36 +84,90 @@ impl World {
         determine_hit(intersections)
     }
 
-    pub fn light(&self) -> &PointLight {
-        &self.light
+    pub fn lights(&self) -> &Vec<Rc<dyn Light>> {
+        &self.lights
+    }
+
+    /// Replaces all of the lights in the world with just this one.
+    pub fn set_light(&mut self, light: Rc<dyn Light>) {
+        self.lights = vec![light];
+    }
+
+    pub fn set_lights(&mut self, lights: Vec<Rc<dyn Light>>) {
+        self.lights = lights;
     }
 
-    pub fn set_light(&mut self, light: PointLight) {
-        self.light = light;
+    pub fn add_light(&mut self, light: Rc<dyn Light>) {
+        self.lights.push(light);
     }
 
     pub fn add_sphere(&mut self, sphere: Sphere) {
+        self.bvh = OnceCell::new();
         self.shapes.push(Rc::new(sphere));
     }
 
     pub fn add_plane(&mut self, plane: Plane) {
+        self.bvh = OnceCell::new();
         self.shapes.push(Rc::new(plane));
     }
 
-    pub fn is_point_shadowed(&self, point: &Point) -> bool {
-        let point_to_light_vector = *self.light.position() - point.to_owned();
-        let point_to_light_ray = Ray::new(point.to_owned(), point_to_light_vector.normalize());
+    /// Finds how much light gets from a light at the given position to the point, which is white
+    /// when nothing is in the way and black when the point is in full shadow.
+    pub fn transmittance_between(&self, point: &Point, light_position: &Point) -> Color {
+        let point_to_light_vector = *light_position - point.to_owned();
 
-        let possible_hit = self.hit_for(&point_to_light_ray);
+        self.transmittance_along(
+            point,
+            &point_to_light_vector.normalize(),
+            point_to_light_vector.magnitude(),
+        )
+    }
+
+    /// Finds how much light gets to the point from a light that's `distance` away from it in the
+    /// given direction. A light that's infinitely far away (like a directional light) is blocked by
+    /// anything at all in that direction.
+    ///
+    /// Each surface of a transparent shape that's in the way lets through as much of the light as
+    /// its transparency, tinted by its color. Anything else (or a material with an opaque shadow)
+    /// blocks the light completely.
+    pub fn transmittance_along(&self, point: &Point, direction: &Vector, distance: f64) -> Color {
+        let point_to_light_ray =
+            Ray::new_of_kind(point.to_owned(), direction.to_owned(), RayKind::Shadow);
+
+        let mut transmittance = WHITE;
+
+        for intersection in self.intersections_for(&point_to_light_ray) {
+            if *intersection.t() <= 0.0 {
+                continue;
+            }
 
-        if let Some(hit) = possible_hit {
-            let distance_from_point_to_light = point_to_light_vector.magnitude();
-            if *hit.t() < distance_from_point_to_light {
-                return true;
+            if *intersection.t() >= distance {
+                break;
             }
+
+            let material = intersection.material();
+            if *material.transparency() == 0.0 || material.opaque_shadow() {
+                return BLACK;
+            }
+
+            let surface_color = material.color_at(&intersection.point(), &intersection);
+            transmittance = transmittance * surface_color * *material.transparency();
         }
 
-        return false;
+        transmittance
+    }
+
+    /// Finds how much of the light gets to the point, averaged over all of its samples.
+    pub fn light_transmittance_at(&self, point: &Point, light: &dyn Light) -> Color {
+        let samples = light.samples_for(point);
+
+        let mut transmittance = BLACK;
+        for sample in &samples {
+            transmittance = transmittance
+                + self.transmittance_along(point, sample.direction(), sample.distance());
+        }
+
+        transmittance * (1.0 / samples.len() as f64)
     }
 
     pub fn shapes(&self) -> &Vec<Rc<dyn Shape>> {
@@ -103,10 +175,12 @@ impl World {
     }
 
     pub fn set_shapes(&mut self, shapes: Vec<Rc<dyn Shape>>) {
+        self.bvh = OnceCell::new();
         self.shapes = shapes;
     }
 
     pub fn add_shape(&mut self, shape: Rc<dyn Shape>) {
+        self.bvh = OnceCell::new();
         self.shapes.push(shape);
     }
 
@@ -142,31 +216,334 @@ mod test {
     fn there_is_no_shadow_when_nothing_is_collinear_with_point_and_light() {
         let world = World::create_default();
         let point = Point::new(0.0, 10.0, 0.0);
-        let result = world.is_point_shadowed(&point);
-        assert_eq!(result, false);
+        let result = world.transmittance_between(&point, &Point::new(-10.0, 10.0, -10.0));
+        assert_eq!(result, WHITE);
     }
 
     #[test]
     fn a_point_on_the_opposite_side_of_an_object_to_a_sphere_is_shadowed() {
         let world = World::create_default();
         let point = Point::new(10.0, -10.0, 10.0);
-        let result = world.is_point_shadowed(&point);
-        assert_eq!(result, true);
+        let result = world.transmittance_between(&point, &Point::new(-10.0, 10.0, -10.0));
+        assert_eq!(result, BLACK);
     }
 
     #[test]
     fn when_the_light_is_between_the_object_and_point_there_is_no_shadow() {
         let world = World::create_default();
         let point = Point::new(-20.0, 20.0, -20.0);
-        let result = world.is_point_shadowed(&point);
-        assert_eq!(result, false);
+        let result = world.transmittance_between(&point, &Point::new(-10.0, 10.0, -10.0));
+        assert_eq!(result, WHITE);
     }
 
     #[test]
     fn when_the_point_is_between_the_object_and_light_there_is_no_shadow() {
         let world = World::create_default();
         let point = Point::new(-2.0, 2.0, -2.0);
-        let result = world.is_point_shadowed(&point);
-        assert_eq!(result, false);
+        let result = world.transmittance_between(&point, &Point::new(-10.0, 10.0, -10.0));
+        assert_eq!(result, WHITE);
+    }
+
+    #[test]
+    fn the_hierarchy_gives_the_same_intersections_as_checking_every_shape() {
+        let mut world = World::new();
+        world.add_shape(Rc::new(Plane::new()));
+        for i in 0..50 {
+            let mut sphere = Sphere::new();
+            sphere.set_transform(Transform::translation(
+                (i % 5) as f64 * 1.5,
+                (i / 5) as f64 * 0.7,
+                (i % 3) as f64,
+            ));
+            world.add_shape(Rc::new(sphere));
+        }
+
+        let ray = Ray::new(
+            Point::new(-2.0, 3.0, -6.0),
+            Vector::new(0.4, 0.1, 1.0).normalize(),
+        );
+
+        let mut every_intersection = Vec::new();
+        for shape in world.shapes() {
+            every_intersection.append(&mut Intersection::of(shape, &ray));
+        }
+        every_intersection.sort_by(|a, b| a.t().total_cmp(b.t()));
+
+        let intersections = world.intersections_for(&ray);
+
+        assert!(!intersections.is_empty());
+        assert!(intersections == every_intersection);
+    }
+
+    #[test]
+    fn a_point_is_only_shadowed_from_the_lights_that_are_blocked() {
+        let mut world = World::create_default();
+        world.add_light(Rc::new(PointLight::new(
+            WHITE,
+            Point::new(10.0, -10.0, 10.0),
+        )));
+
+        // This point is behind the spheres from the first light, but right next to the second.
+        let point = Point::new(9.0, -9.0, 9.0);
+
+        assert_eq!(
+            world.light_transmittance_at(&point, world.lights()[0].as_ref()),
+            BLACK
+        );
+        assert_eq!(
+            world.light_transmittance_at(&point, world.lights()[1].as_ref()),
+            WHITE
+        );
+    }
+
+    #[test]
+    fn anything_in_the_way_of_an_infinitely_distant_light_casts_a_shadow() {
+        let world = World::create_default();
+        let point = Point::new(0.0, 0.0, -1000.0);
+
+        // Even though the spheres are a long way off, there's nothing beyond the light.
+        assert_eq!(
+            world.transmittance_along(&point, &POSITIVE_Z, f64::INFINITY),
+            BLACK
+        );
+        assert_eq!(
+            world.transmittance_along(&point, &NEGATIVE_Z, f64::INFINITY),
+            WHITE
+        );
+        assert_eq!(world.transmittance_along(&point, &POSITIVE_Z, 500.0), WHITE);
+    }
+
+    #[test]
+    fn a_directional_light_has_parallel_shadows() {
+        let world = World::create_default();
+        let light = DirectionalLight::new(WHITE, NEGATIVE_Y);
+
+        // A point light above the spheres would light these points from the side, but the sun
+        // shines straight down on them.
+        let cases = [
+            (Point::new(0.0, -1.0001, 0.0), 0.0),
+            (Point::new(0.9, -50.0, 0.0), 0.0),
+            (Point::new(1.1, -50.0, 0.0), 1.0),
+            (Point::new(0.0, 1.0001, 0.0), 1.0),
+        ];
+
+        for (point, expected) in cases {
+            assert_eq!(
+                world.light_transmittance_at(&point, &light),
+                WHITE * expected,
+                "{:?}",
+                point
+            );
+        }
+    }
+
+    #[test]
+    fn all_or_none_of_a_point_light_gets_to_a_point() {
+        let world = World::create_default();
+        let light = world.lights()[0].as_ref();
+
+        let cases = [
+            (Point::new(0.0, 1.0001, 0.0), 1.0),
+            (Point::new(-1.0001, 0.0, 0.0), 1.0),
+            (Point::new(0.0, 0.0, -1.0001), 1.0),
+            (Point::new(0.0, 0.0, 1.0001), 0.0),
+            (Point::new(1.0001, 0.0, 0.0), 0.0),
+            (Point::new(0.0, -1.0001, 0.0), 0.0),
+            (Point::new(0.0, 0.0, 0.0), 0.0),
+        ];
+
+        for (point, expected) in cases {
+            assert_eq!(
+                world.light_transmittance_at(&point, light),
+                WHITE * expected,
+                "{:?}",
+                point
+            );
+        }
+    }
+
+    #[test]
+    fn part_of_an_area_light_can_get_to_a_point() {
+        let world = World::create_default();
+        let light = AreaLight::new(
+            Point::new(-0.5, -0.5, -5.0),
+            Vector::new(1.0, 0.0, 0.0),
+            2,
+            Vector::new(0.0, 1.0, 0.0),
+            2,
+            WHITE,
+        );
+
+        let cases = [
+            (Point::new(0.0, 0.0, 2.0), 0.0),
+            (Point::new(1.0, -1.0, 2.0), 0.25),
+            (Point::new(1.5, 0.0, 2.0), 0.5),
+            (Point::new(1.25, 1.25, 3.0), 0.75),
+            (Point::new(0.0, 0.0, -2.0), 1.0),
+        ];
+
+        for (point, expected) in cases {
+            assert_eq!(
+                world.light_transmittance_at(&point, &light),
+                WHITE * expected,
+                "{:?}",
+                point
+            );
+        }
+    }
+
+    fn world_with_a_pane_between_the_light_and_the_origin(material: Material) -> World {
+        let mut pane = Plane::new();
+        pane.set_transform(Transform::translation(0.0, 1.0, 0.0));
+        pane.set_material(material);
+
+        let mut world = World::new();
+        world.set_light(Rc::new(PointLight::new(WHITE, Point::new(0.0, 10.0, 0.0))));
+        world.add_shape(Rc::new(pane));
+        world
+    }
+
+    #[test]
+    fn a_transparent_shape_lets_some_of_the_light_through() {
+        let world = world_with_a_pane_between_the_light_and_the_origin(
+            MaterialBuilder::new().transparency(0.8).build(),
+        );
+
+        let transmittance = world.light_transmittance_at(&ORIGIN, world.lights()[0].as_ref());
+
+        assert_eq!(transmittance, WHITE * 0.8);
+    }
+
+    #[test]
+    fn the_light_through_a_transparent_shape_is_tinted_by_its_color() {
+        let world = world_with_a_pane_between_the_light_and_the_origin(
+            MaterialBuilder::new()
+                .flat_color(Color::new(1.0, 0.5, 0.0))
+                .transparency(0.5)
+                .build(),
+        );
+
+        let transmittance = world.light_transmittance_at(&ORIGIN, world.lights()[0].as_ref());
+
+        assert_eq!(transmittance, Color::new(0.5, 0.25, 0.0));
+    }
+
+    #[test]
+    fn every_surface_in_the_way_lets_less_of_the_light_through() {
+        let mut world = world_with_a_pane_between_the_light_and_the_origin(
+            MaterialBuilder::new().transparency(0.5).build(),
+        );
+
+        let mut glass_ball = Sphere::new();
+        glass_ball.set_transform(Transform::translation(0.0, 5.0, 0.0));
+        glass_ball.set_material(MaterialBuilder::new().transparency(0.5).build());
+        world.add_shape(Rc::new(glass_ball));
+
+        let transmittance = world.light_transmittance_at(&ORIGIN, world.lights()[0].as_ref());
+
+        // Once through the pane, and in and out of the ball.
+        assert_eq!(transmittance, WHITE * 0.125);
+    }
+
+    #[test]
+    fn a_material_can_still_cast_an_opaque_shadow() {
+        let world = world_with_a_pane_between_the_light_and_the_origin(
+            MaterialBuilder::new()
+                .transparency(0.8)
+                .opaque_shadow(true)
+                .build(),
+        );
+
+        let transmittance = world.light_transmittance_at(&ORIGIN, world.lights()[0].as_ref());
+
+        assert_eq!(transmittance, BLACK);
+    }
+
+    fn world_with_a_sphere_that_has(visibility: Visibility) -> World {
+        let mut sphere = Sphere::new();
+        sphere.set_visibility(visibility);
+
+        let mut world = World::new();
+        world.add_shape(Rc::new(sphere));
+        world
+    }
+
+    #[test]
+    fn a_shape_can_be_hidden_from_the_camera() {
+        let world = world_with_a_sphere_that_has(Visibility::new(true, false, true));
+
+        let camera_ray = Ray::new(Point::new(0.0, 0.0, -5.0), POSITIVE_Z);
+        let reflected_ray =
+            Ray::new_of_kind(Point::new(0.0, 0.0, -5.0), POSITIVE_Z, RayKind::Secondary);
+
+        assert!(world.hit_for(&camera_ray).is_none());
+        assert!(world.hit_for(&reflected_ray).is_some());
+    }
+
+    #[test]
+    fn a_shape_can_be_hidden_from_reflections() {
+        let world = world_with_a_sphere_that_has(Visibility::new(true, true, false));
+
+        let camera_ray = Ray::new(Point::new(0.0, 0.0, -5.0), POSITIVE_Z);
+        let reflected_ray =
+            Ray::new_of_kind(Point::new(0.0, 0.0, -5.0), POSITIVE_Z, RayKind::Secondary);
+
+        assert!(world.hit_for(&camera_ray).is_some());
+        assert!(world.hit_for(&reflected_ray).is_none());
+    }
+
+    #[test]
+    fn a_shape_that_does_not_cast_a_shadow_lets_the_light_through() {
+        let light_position = Point::new(0.0, 0.0, -10.0);
+        let point = Point::new(0.0, 0.0, 10.0);
+
+        let world = world_with_a_sphere_that_has(Visibility::new(false, true, true));
+        assert_eq!(world.transmittance_between(&point, &light_position), WHITE);
+
+        // And one that can't be seen can still cast a shadow.
+        let world = world_with_a_sphere_that_has(Visibility::new(true, false, false));
+        assert_eq!(world.transmittance_between(&point, &light_position), BLACK);
+    }
+
+    #[test]
+    fn the_visibility_of_a_group_applies_to_its_children() {
+        let mut group = Group::new();
+        group.add_child(Rc::new(Sphere::new()));
+        group.set_visibility(Visibility::new(true, false, true));
+
+        let mut world = World::new();
+        world.add_shape(Rc::new(group));
+
+        let ray = Ray::new(Point::new(0.0, 0.0, -5.0), POSITIVE_Z);
+        assert!(world.hit_for(&ray).is_none());
+    }
+
+    #[test]
+    fn a_group_and_its_children_can_each_hide_something_different() {
+        let mut sphere = Sphere::new();
+        sphere.set_visibility(Visibility::new(true, false, true));
+        let sphere: Rc<dyn Shape> = Rc::new(sphere);
+
+        let mut group = Group::new();
+        group.add_child(sphere.clone());
+        group.set_visibility(Visibility::new(false, true, true));
+
+        let mut world = World::new();
+        world.add_shape(Rc::new(group));
+
+        // The sphere still can't be seen by the camera...
+        let ray = Ray::new(Point::new(0.0, 0.0, -5.0), POSITIVE_Z);
+        assert!(world.hit_for(&ray).is_none());
+
+        // ...and the group stops it from casting a shadow too.
+        let point = Point::new(0.0, 0.0, -5.0);
+        let light_position = Point::new(0.0, 0.0, 5.0);
+        assert_eq!(world.transmittance_between(&point, &light_position), WHITE);
+
+        // It's still there for reflections, and the sphere itself was left alone.
+        let reflected_ray =
+            Ray::new_of_kind(Point::new(0.0, 0.0, -5.0), POSITIVE_Z, RayKind::Secondary);
+        assert!(world.hit_for(&reflected_ray).is_some());
+        assert_eq!(*sphere.visibility(), Visibility::new(true, false, true));
     }
 }
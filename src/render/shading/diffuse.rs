@@ -1,8 +1,8 @@
 use crate::prelude::*;
 use crate::render::shading::shade_ray::adjust_hit_over;
 
-pub fn calculate_diffuse_contribution(light: &PointLight, hit: &Intersection) -> Color {
-    let light_vector = (*light.position() - hit.point()).normalize();
+pub fn calculate_diffuse_contribution(sample: &LightSample, hit: &Intersection) -> Color {
+    let light_vector = *sample.direction();
 
     let light_dot_normal = dot(&light_vector, &hit.normal_vector());
 
@@ -11,9 +11,7 @@ pub fn calculate_diffuse_contribution(light: &PointLight, hit: &Intersection) ->
     }
 
     let adjusted_hit = adjust_hit_over(&hit);
-    let hit_in_object_space = hit.object().transform().invert().unwrap() * adjusted_hit;
-
-    let effective_color = hit.material().color_at(&hit_in_object_space) * *light.intensity();
+    let effective_color = hit.material().color_at(&adjusted_hit, hit) * *sample.intensity();
 
     let light_dot_normal = dot(&light_vector, &hit.normal_vector());
 
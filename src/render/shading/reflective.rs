@@ -14,7 +14,7 @@ pub fn calculate_reflective_contribution(
 
     shade_ray_with_maximum_recursion(
         world,
-        &Ray::new(adjusted_hit, reflection_vector),
+        &Ray::new_of_kind(adjusted_hit, reflection_vector, RayKind::Secondary),
         current_recursion_count + 1,
     ) * *material.reflective()
 }
@@ -34,7 +34,11 @@ pub fn calculate_refractive_contribution(
     let direction_of_refracted_ray =
         normal_vector * (refractive_ratio * cos_i - cos_t) - eye_vector * refractive_ratio;
 
-    let refracted_ray = Ray::new(adjust_hit_under(hit), direction_of_refracted_ray);
+    let refracted_ray = Ray::new_of_kind(
+        adjust_hit_under(hit),
+        direction_of_refracted_ray,
+        RayKind::Secondary,
+    );
 
     shade_ray_with_maximum_recursion(world, &refracted_ray, current_recursion_count + 1)
         * *hit.material().transparency()
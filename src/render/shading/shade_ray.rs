@@ -51,18 +51,34 @@ fn shade_hit(world: &World, hit: &Intersection, current_recursion_count: i8) ->
     color
 }
 
+// Each light adds its own contribution to the color. Its diffuse and specular parts are averaged
+// over all of its samples, each one scaled by how much of the light gets through from that sample.
 fn calculate_surface_color(world: &World, hit: &Intersection) -> Color {
-    let light = world.light();
-
-    let ambient_contribution = calculate_ambient_contribution(light, hit);
+    let adjusted_hit = adjust_hit_over(hit);
+    let mut color = BLACK;
+
+    for light in world.lights() {
+        color = color + calculate_ambient_contribution(light.as_ref(), hit);
+
+        let samples = light.samples_for(&hit.point());
+        let mut lit_color = BLACK;
+        for sample in &samples {
+            let transmittance =
+                world.transmittance_along(&adjusted_hit, sample.direction(), sample.distance());
+            if transmittance == BLACK {
+                continue;
+            }
+
+            lit_color = lit_color
+                + (calculate_diffuse_contribution(sample, hit)
+                    + calculate_specular_contribution(sample, hit))
+                    * transmittance;
+        }
 
-    if world.is_point_shadowed(&adjust_hit_over(&hit)) {
-        return ambient_contribution;
+        color = color + lit_color * (1.0 / samples.len() as f64);
     }
 
-    ambient_contribution
-        + calculate_diffuse_contribution(light, hit)
-        + calculate_specular_contribution(light, hit)
+    color
 }
 
 // This adjusts the hit so that it's ever so slightly on the outside of the intersected shape.
@@ -90,12 +106,134 @@ mod test {
         assert_eq!(result, Color::new(0.38066, 0.47583, 0.2855))
     }
 
+    #[test]
+    fn each_light_adds_to_the_shading() {
+        let mut world = World::create_default();
+        world.add_light(world.lights()[0].clone());
+        let ray = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+
+        let result = shade_ray(&world, &ray);
+
+        assert_eq!(result, Color::new(0.38066, 0.47583, 0.2855) * 2.0)
+    }
+
+    #[test]
+    fn a_light_that_is_blocked_only_adds_its_ambient_contribution() {
+        let mut world = World::create_default();
+        // This light is on the other side of the spheres, so all of the hit is in its shadow.
+        world.add_light(Rc::new(PointLight::new(WHITE, Point::new(0.0, 0.0, 10.0))));
+        let ray = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+
+        let result = shade_ray(&world, &ray);
+
+        let ambient_only = Color::new(0.8, 1.0, 0.6) * 0.1;
+        assert_eq!(result, Color::new(0.38066, 0.47583, 0.2855) + ambient_only)
+    }
+
+    #[test]
+    fn an_area_light_with_one_cell_shades_like_a_point_light_in_the_middle_of_it() {
+        let mut world = World::create_default();
+        world.set_light(Rc::new(AreaLight::new(
+            Point::new(-11.0, 10.0, -11.0),
+            Vector::new(2.0, 0.0, 0.0),
+            1,
+            Vector::new(0.0, 0.0, 2.0),
+            1,
+            WHITE,
+        )));
+        let ray = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+
+        let result = shade_ray(&world, &ray);
+
+        assert_eq!(result, Color::new(0.38066, 0.47583, 0.2855))
+    }
+
+    #[test]
+    fn an_area_light_that_is_partly_blocked_lights_a_point_partly() {
+        let mut world = World::create_default();
+        // One of the two cells of this light is on the far side of the spheres from the hit.
+        world.set_light(Rc::new(AreaLight::new(
+            Point::new(-8.0, -0.5, 0.0),
+            Vector::new(16.0, 0.0, 0.0),
+            2,
+            Vector::new(0.0, 1.0, 0.0),
+            1,
+            WHITE,
+        )));
+        let ray = Ray::new(Point::new(-5.0, 0.0, 0.0), POSITIVE_X);
+        let hit = world.hit_for(&ray).unwrap();
+
+        let transmittance =
+            world.light_transmittance_at(&adjust_hit_over(&hit), world.lights()[0].as_ref());
+        assert_eq!(transmittance, WHITE * 0.5);
+
+        let result = shade_ray(&world, &ray);
+        let ambient_only = Color::new(0.8, 1.0, 0.6) * 0.1;
+        assert!(*result.g() > *ambient_only.g());
+    }
+
+    #[test]
+    fn each_sample_of_an_area_light_is_only_lit_if_it_gets_through() {
+        let mut world = World::create_default();
+        // Just like above, but with the spots moving around their cells.
+        let mut light = AreaLight::new(
+            Point::new(-8.0, -0.5, 0.0),
+            Vector::new(16.0, 0.0, 0.0),
+            2,
+            Vector::new(0.0, 1.0, 0.0),
+            1,
+            WHITE,
+        );
+        light.set_jitter(true);
+        world.set_light(Rc::new(light));
+
+        let ray = Ray::new(Point::new(-5.0, 0.0, 0.0), POSITIVE_X);
+        let hit = world.hit_for(&ray).unwrap();
+
+        // Only the first cell, on the same side of the spheres as the hit, lights it.
+        let samples = light.samples_for(&hit.point());
+        let expected = calculate_ambient_contribution(&light, &hit)
+            + (calculate_diffuse_contribution(&samples[0], &hit)
+                + calculate_specular_contribution(&samples[0], &hit))
+                * 0.5;
+
+        assert_eq!(shade_ray(&world, &ray), expected);
+    }
+
+    #[test]
+    fn a_spot_light_only_lights_what_is_inside_its_cone() {
+        let mut world = World::create_default();
+        let ray = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+
+        world.set_light(Rc::new(SpotLight::new(
+            WHITE,
+            Point::new(-10.0, 10.0, -10.0),
+            Vector::new(10.0, -10.0, 9.0),
+            0.1,
+            0.2,
+        )));
+        assert_eq!(
+            shade_ray(&world, &ray),
+            Color::new(0.38066, 0.47583, 0.2855)
+        );
+
+        world.set_light(Rc::new(SpotLight::new(
+            WHITE,
+            Point::new(-10.0, 10.0, -10.0),
+            POSITIVE_Y,
+            0.1,
+            0.2,
+        )));
+        let ambient_only = Color::new(0.8, 1.0, 0.6) * 0.1;
+        assert_eq!(shade_ray(&world, &ray), ambient_only);
+    }
+
     #[test]
     fn shading_an_intersection_from_the_inside() {
         let mut world = World::create_default();
 
         let light = PointLight::new(Color::new(1.0, 1.0, 1.0), Point::new(0.0, 0.25, 0.0));
-        world.set_light(light);
+        world.set_light(Rc::new(light));
 
         let ray = Ray::new(Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0));
 
@@ -271,7 +409,7 @@ mod test {
         let mut world = World::new();
         world.add_sphere(first_sphere);
         world.add_sphere(second_sphere);
-        world.set_light(light);
+        world.set_light(Rc::new(light));
 
         // This ray originates inside of the outermost sphere, and is pointed at the inner one.
         let ray = Ray::new(Point::new(0.0, 0.0, 0.75), Vector::new(0.0, 0.0, -1.0));
@@ -285,10 +423,10 @@ mod test {
     #[test]
     fn getting_the_color_for_a_shaded_hit() {
         let mut world = World::new();
-        world.set_light(PointLight::new(
+        world.set_light(Rc::new(PointLight::new(
             Color::new(1.0, 1.0, 1.0),
             Point::new(0.0, 0.0, -10.0),
-        ));
+        )));
 
         let sphere_one = Sphere::new();
         world.add_sphere(sphere_one);
@@ -330,7 +468,7 @@ mod test {
     #[test]
     fn two_reflective_surfaces_do_not_cause_the_program_to_fail() {
         let mut world = World::new();
-        world.set_light(PointLight::new(WHITE, ORIGIN));
+        world.set_light(Rc::new(PointLight::new(WHITE, ORIGIN)));
 
         let material_a = MaterialBuilder::new().reflective(1.0).build();
         let material_b = MaterialBuilder::new().reflective(1.0).build();
@@ -360,6 +498,8 @@ mod test {
             MaterialBuilder::new()
                 .transparency(0.5)
                 .refractive_index(1.5)
+                // These values were worked out with the floor casting a solid shadow on the ball.
+                .opaque_shadow(true)
                 .build(),
         );
 
@@ -396,6 +536,8 @@ mod test {
                 .transparency(0.5)
                 .reflective(0.5)
                 .refractive_index(1.5)
+                // These values were worked out with the floor casting a solid shadow on the ball.
+                .opaque_shadow(true)
                 .build(),
         );
 
@@ -1,9 +1,9 @@
 use crate::prelude::*;
 
-pub fn calculate_specular_contribution(light: &PointLight, hit: &Intersection) -> Color {
+pub fn calculate_specular_contribution(sample: &LightSample, hit: &Intersection) -> Color {
     let normal_vector = &hit.normal_vector();
     let material = hit.material();
-    let light_vector = (*light.position() - hit.point()).normalize();
+    let light_vector = *sample.direction();
 
     let eye_vector = -hit.ray().direction().to_owned();
 
@@ -21,6 +21,6 @@ pub fn calculate_specular_contribution(light: &PointLight, hit: &Intersection) -
         return BLACK;
     } else {
         let specular_factor = reflection_dot_eye.powf(*material.shininess());
-        return *light.intensity() * *material.specular() * specular_factor;
+        return *sample.intensity() * *material.specular() * specular_factor;
     }
 }